@@ -0,0 +1,37 @@
+/// Byte order of the multi-byte fields in the value byte format.
+///
+/// Little-endian is the default; LEB128 lengths and single-byte fields are
+/// the same in either order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteOrder {
+    #[default]
+    Little,
+    Big,
+}
+
+/// Fixed-width numbers that can be written in either byte order.
+pub(crate) trait FixedWidth<const N: usize> {
+    fn to_bytes(self, order: ByteOrder) -> [u8; N];
+    fn from_bytes(bytes: [u8; N], order: ByteOrder) -> Self;
+}
+
+macro_rules! impl_fixed_width {
+    ($($t:ty => $n:literal), *) => {
+        $(impl FixedWidth<$n> for $t {
+            fn to_bytes(self, order: ByteOrder) -> [u8; $n] {
+                match order {
+                    ByteOrder::Little => self.to_le_bytes(),
+                    ByteOrder::Big => self.to_be_bytes(),
+                }
+            }
+
+            fn from_bytes(bytes: [u8; $n], order: ByteOrder) -> Self {
+                match order {
+                    ByteOrder::Little => <$t>::from_le_bytes(bytes),
+                    ByteOrder::Big => <$t>::from_be_bytes(bytes),
+                }
+            }
+        })*
+    };
+}
+impl_fixed_width!(i64 => 8, f64 => 8, u32 => 4, f32 => 4);
@@ -0,0 +1,22 @@
+use crate::byte_order::ByteOrder;
+
+/// How `Int` payloads are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntEncoding {
+    /// Eight bytes in the configured byte order.
+    #[default]
+    Fixed,
+    /// Signed LEB128, so values in `-64..=63` take a single byte.
+    Leb128,
+}
+
+/// Options for the value byte format.
+///
+/// The default is the fixed-width little-endian format used by
+/// `From<Value> for Vec<u8>`. A reader must decode with the same options the
+/// writer used, since nothing in the encoded value records them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Encoding {
+    pub order: ByteOrder,
+    pub ints: IntEncoding,
+}
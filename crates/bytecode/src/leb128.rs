@@ -0,0 +1,71 @@
+//! LEB128 variable-length integers used for length prefixes.
+
+/// Appends `value` to `buffer` as unsigned LEB128.
+pub(crate) fn write_unsigned(buffer: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer.push(byte);
+            return;
+        }
+        buffer.push(byte | 0x80);
+    }
+}
+
+/// Reads an unsigned LEB128 integer from the start of `bytes`, returning it
+/// with the number of bytes consumed, or `None` if it is truncated or does
+/// not fit in a `u64`.
+pub(crate) fn read_unsigned(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        let chunk = (byte & 0x7f) as u64;
+        if shift >= u64::BITS || (chunk << shift) >> shift != chunk {
+            return None;
+        }
+        value |= chunk << shift;
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// Appends `value` to `buffer` as signed LEB128.
+pub(crate) fn write_signed(buffer: &mut Vec<u8>, mut value: i64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let sign_bit_clear = byte & 0x40 == 0;
+        if (value == 0 && sign_bit_clear) || (value == -1 && !sign_bit_clear) {
+            buffer.push(byte);
+            return;
+        }
+        buffer.push(byte | 0x80);
+    }
+}
+
+/// Reads a signed LEB128 integer from the start of `bytes`, returning it with
+/// the number of bytes consumed, or `None` if it is truncated or longer than
+/// an `i64` needs.
+pub(crate) fn read_signed(bytes: &[u8]) -> Option<(i64, usize)> {
+    let mut value = 0i64;
+    let mut shift = 0u32;
+    for (i, byte) in bytes.iter().enumerate() {
+        // The tenth byte holds only bit 63, so the rest of it must repeat
+        // that bit for the value to fit.
+        if shift >= i64::BITS || (shift == i64::BITS - 1 && *byte != 0x00 && *byte != 0x7f) {
+            return None;
+        }
+        value |= ((byte & 0x7f) as i64) << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            if shift < i64::BITS && byte & 0x40 != 0 {
+                value |= -1i64 << shift;
+            }
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
@@ -1,3 +1,6 @@
+pub mod byte_order;
+pub mod encoding;
+mod leb128;
 pub mod opcode;
 pub mod types;
 pub mod values;
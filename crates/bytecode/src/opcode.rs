@@ -1,6 +1,7 @@
 use thiserror::Error;
 
-/// Hexadecimals with this template are OpCodes 0x1_
+/// Hexadecimals with these templates are OpCodes 0x1_ and 0x3_ (0x2_ is
+/// taken by Types)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum OpCode {
     Constant = 0x10,
@@ -10,6 +11,173 @@ pub enum OpCode {
     Multiply = 0x14,
     Divide = 0x15,
     Return = 0x16,
+    /// Pushes its single signed byte operand as `Value::Int`, skipping the
+    /// constant pool for integers in `-128..=127`.
+    PushInt8 = 0x17,
+    True = 0x18,
+    False = 0x19,
+    /// Calls the function on the stack; its single byte operand is the
+    /// number of arguments.
+    Call = 0x1A,
+    BitAnd = 0x1B,
+    BitOr = 0x1C,
+    BitXor = 0x1D,
+    ShiftLeft = 0x1E,
+    ShiftRight = 0x1F,
+    StrLen = 0x30,
+    StrCharAt = 0x31,
+    /// Pops as many values as its single byte operand says.
+    PopN = 0x32,
+    /// Returns the top of the stack after discarding the number of slots
+    /// below it given by its single byte operand.
+    ReturnN = 0x33,
+    LessEqual = 0x34,
+    GreaterEqual = 0x35,
+    /// Pops the exponent, then the base.
+    Power = 0x36,
+    /// Pops the condition and fails execution if it is falsy.
+    Assert = 0x37,
+    /// Rotates the top values of the stack; its single byte operand is how
+    /// many. The deepest of them moves to the top, so `Rotate 2` is a swap.
+    Rotate = 0x38,
+    /// Pops as many values as its single byte operand says into a `Tuple`,
+    /// the first pushed becoming element 0.
+    MakeTuple = 0x39,
+    /// Pops a value and pushes its length; see `Value::len`.
+    Len = 0x3A,
+    /// Does nothing; left behind by patching or used as alignment padding.
+    Nop = 0x3B,
+    /// Pops a value and writes its `Display` form with no trailing newline.
+    Print = 0x3C,
+    /// Pops a value and writes its `Display` form followed by a newline.
+    PrintLn = 0x3D,
+    /// Ends the whole program with the top of the stack, whatever the call
+    /// depth; `Return` only leaves the current function.
+    Halt = 0x3E,
+    /// Pops the epsilon, then the two floats, and pushes whether they differ
+    /// by at most the epsilon.
+    ApproxEqual = 0x3F,
+}
+
+impl OpCode {
+    const ALL: &'static [OpCode] = &[
+        OpCode::Constant,
+        OpCode::Negate,
+        OpCode::Add,
+        OpCode::Subtract,
+        OpCode::Multiply,
+        OpCode::Divide,
+        OpCode::Return,
+        OpCode::PushInt8,
+        OpCode::True,
+        OpCode::False,
+        OpCode::Call,
+        OpCode::BitAnd,
+        OpCode::BitOr,
+        OpCode::BitXor,
+        OpCode::ShiftLeft,
+        OpCode::ShiftRight,
+        OpCode::StrLen,
+        OpCode::StrCharAt,
+        OpCode::PopN,
+        OpCode::ReturnN,
+        OpCode::LessEqual,
+        OpCode::GreaterEqual,
+        OpCode::Power,
+        OpCode::Assert,
+        OpCode::Rotate,
+        OpCode::MakeTuple,
+        OpCode::Len,
+        OpCode::Nop,
+        OpCode::Print,
+        OpCode::PrintLn,
+        OpCode::Halt,
+        OpCode::ApproxEqual,
+    ];
+
+    /// Every opcode, in encoding order.
+    pub fn all() -> &'static [OpCode] {
+        Self::ALL
+    }
+
+    /// Whether this pops two numeric operands and pushes one result.
+    pub fn is_binary_arithmetic(&self) -> bool {
+        matches!(
+            self,
+            OpCode::Add
+                | OpCode::Subtract
+                | OpCode::Multiply
+                | OpCode::Divide
+                | OpCode::BitAnd
+                | OpCode::BitOr
+                | OpCode::BitXor
+                | OpCode::ShiftLeft
+                | OpCode::ShiftRight
+                | OpCode::Power
+        )
+    }
+
+    /// Whether this pops two operands and pushes a `Bool` ordering result.
+    pub fn is_comparison(&self) -> bool {
+        matches!(self, OpCode::LessEqual | OpCode::GreaterEqual)
+    }
+
+    /// Whether execution never falls through to the next instruction.
+    pub fn is_terminator(&self) -> bool {
+        matches!(self, OpCode::Return | OpCode::ReturnN | OpCode::Halt)
+    }
+
+    /// Mnemonic used when printing bytecode.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OpCode::Constant => "CONSTANT",
+            OpCode::Negate => "NEGATE",
+            OpCode::Add => "ADD",
+            OpCode::Subtract => "SUBTRACT",
+            OpCode::Multiply => "MULTIPLY",
+            OpCode::Divide => "DIVIDE",
+            OpCode::Return => "RETURN",
+            OpCode::PushInt8 => "PUSH_INT8",
+            OpCode::True => "TRUE",
+            OpCode::False => "FALSE",
+            OpCode::Call => "CALL",
+            OpCode::BitAnd => "BIT_AND",
+            OpCode::BitOr => "BIT_OR",
+            OpCode::BitXor => "BIT_XOR",
+            OpCode::ShiftLeft => "SHIFT_LEFT",
+            OpCode::ShiftRight => "SHIFT_RIGHT",
+            OpCode::StrLen => "STR_LEN",
+            OpCode::StrCharAt => "STR_CHAR_AT",
+            OpCode::PopN => "POP_N",
+            OpCode::ReturnN => "RETURN_N",
+            OpCode::LessEqual => "LESS_EQUAL",
+            OpCode::GreaterEqual => "GREATER_EQUAL",
+            OpCode::Power => "POWER",
+            OpCode::Assert => "ASSERT",
+            OpCode::Rotate => "ROTATE",
+            OpCode::MakeTuple => "MAKE_TUPLE",
+            OpCode::Len => "LEN",
+            OpCode::Nop => "NOP",
+            OpCode::Print => "PRINT",
+            OpCode::PrintLn => "PRINT_LN",
+            OpCode::Halt => "HALT",
+            OpCode::ApproxEqual => "APPROX_EQUAL",
+        }
+    }
+
+    /// Number of operand bytes that follow the opcode in the code stream.
+    pub fn operand_bytes(&self) -> usize {
+        match self {
+            OpCode::Constant
+            | OpCode::PushInt8
+            | OpCode::Call
+            | OpCode::PopN
+            | OpCode::ReturnN
+            | OpCode::Rotate
+            | OpCode::MakeTuple => 1,
+            _ => 0,
+        }
+    }
 }
 
 impl TryFrom<u8> for OpCode {
@@ -24,6 +192,31 @@ impl TryFrom<u8> for OpCode {
             0x14 => Ok(OpCode::Multiply),
             0x15 => Ok(OpCode::Divide),
             0x16 => Ok(OpCode::Return),
+            0x17 => Ok(OpCode::PushInt8),
+            0x18 => Ok(OpCode::True),
+            0x19 => Ok(OpCode::False),
+            0x1A => Ok(OpCode::Call),
+            0x1B => Ok(OpCode::BitAnd),
+            0x1C => Ok(OpCode::BitOr),
+            0x1D => Ok(OpCode::BitXor),
+            0x1E => Ok(OpCode::ShiftLeft),
+            0x1F => Ok(OpCode::ShiftRight),
+            0x30 => Ok(OpCode::StrLen),
+            0x31 => Ok(OpCode::StrCharAt),
+            0x32 => Ok(OpCode::PopN),
+            0x33 => Ok(OpCode::ReturnN),
+            0x34 => Ok(OpCode::LessEqual),
+            0x35 => Ok(OpCode::GreaterEqual),
+            0x36 => Ok(OpCode::Power),
+            0x37 => Ok(OpCode::Assert),
+            0x38 => Ok(OpCode::Rotate),
+            0x39 => Ok(OpCode::MakeTuple),
+            0x3A => Ok(OpCode::Len),
+            0x3B => Ok(OpCode::Nop),
+            0x3C => Ok(OpCode::Print),
+            0x3D => Ok(OpCode::PrintLn),
+            0x3E => Ok(OpCode::Halt),
+            0x3F => Ok(OpCode::ApproxEqual),
             _ => Err(OpCodeError::InvalidOpCode(value)),
         }
     }
@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use thiserror::Error;
 
-use crate::values::Value;
+use crate::values::{Value, ValueError};
 
 /// Hexadecimals with this template are Types 0x2_
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,6 +12,67 @@ pub enum Type {
     Bool = 0x22,
     Str = 0x23,
     Char = 0x24,
+    Float32 = 0x28,
+    Bytes = 0x29,
+    Range = 0x2A,
+    Symbol = 0x2B,
+    Tuple = 0x2C,
+}
+
+impl Type {
+    const ALL: &'static [Type] = &[
+        Type::Int,
+        Type::Float,
+        Type::Bool,
+        Type::Str,
+        Type::Char,
+        Type::Float32,
+        Type::Bytes,
+        Type::Range,
+        Type::Symbol,
+        Type::Tuple,
+    ];
+
+    /// Every type, in tag order.
+    pub fn all() -> &'static [Type] {
+        Self::ALL
+    }
+
+    /// Size in bytes of the encoded payload after the tag, or `None` for
+    /// length- and count-prefixed types.
+    pub fn payload_size(&self) -> Option<usize> {
+        match self {
+            Type::Int | Type::Float => Some(8),
+            Type::Float32 | Type::Char => Some(4),
+            Type::Bool => Some(1),
+            Type::Range => Some(17),
+            Type::Str | Type::Bytes | Type::Symbol | Type::Tuple => None,
+        }
+    }
+
+    /// Whether values of this type take part in arithmetic.
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Type::Int | Type::Float | Type::Float32)
+    }
+
+    /// Whether values of this type can be ordered against values of `other`:
+    /// any two numeric types, or two strings, chars, byte blobs or symbols.
+    pub fn is_comparable_with(&self, other: Type) -> bool {
+        match (self, other) {
+            (a, b) if a.is_numeric() && b.is_numeric() => true,
+            (Type::Str, Type::Str)
+            | (Type::Char, Type::Char)
+            | (Type::Bytes, Type::Bytes)
+            | (Type::Symbol, Type::Symbol) => true,
+            _ => false,
+        }
+    }
+
+    /// Reads the type tag at the start of an encoded buffer without
+    /// decoding the payload. An empty buffer is `ValueError::NoTag`.
+    pub fn peek(bytes: &[u8]) -> Result<Type, ValueError> {
+        Type::try_from(bytes)
+    }
 }
 
 impl From<&Value> for Type {
@@ -22,6 +83,11 @@ impl From<&Value> for Type {
             Value::Bool(_) => Self::Bool,
             Value::Str(_) => Self::Str,
             Value::Char(_) => Self::Char,
+            Value::Float32(_) => Self::Float32,
+            Value::Bytes(_) => Self::Bytes,
+            Value::Range { .. } => Self::Range,
+            Value::Symbol(_) => Self::Symbol,
+            Value::Tuple(_) => Self::Tuple,
         }
     }
 }
@@ -36,11 +102,27 @@ impl TryFrom<u8> for Type {
             0x22 => Ok(Type::Bool),
             0x23 => Ok(Type::Str),
             0x24 => Ok(Type::Char),
+            0x28 => Ok(Type::Float32),
+            0x29 => Ok(Type::Bytes),
+            0x2A => Ok(Type::Range),
+            0x2B => Ok(Type::Symbol),
+            0x2C => Ok(Type::Tuple),
             _ => Err(TypeError::InvalidType(value)),
         }
     }
 }
 
+impl TryFrom<&[u8]> for Type {
+    type Error = ValueError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let Some(&tag) = value.first() else {
+            return Err(ValueError::NoTag);
+        };
+        Ok(Type::try_from(tag)?)
+    }
+}
+
 impl Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -49,6 +131,11 @@ impl Display for Type {
             Type::Bool => write!(f, "Bool"),
             Type::Str => write!(f, "String"),
             Type::Char => write!(f, "Char"),
+            Type::Float32 => write!(f, "Float32"),
+            Type::Bytes => write!(f, "Bytes"),
+            Type::Range => write!(f, "Range"),
+            Type::Symbol => write!(f, "Symbol"),
+            Type::Tuple => write!(f, "Tuple"),
         }
     }
 }
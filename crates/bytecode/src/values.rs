@@ -1,14 +1,857 @@
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Shl, Shr, Sub};
+
 use thiserror::Error;
 
+use crate::byte_order::{ByteOrder, FixedWidth};
+use crate::encoding::{Encoding, IntEncoding};
+use crate::leb128;
 use crate::types::{Type, TypeError};
 
+/// A runtime value of the language.
+///
+/// Values compare directly with host primitives (`isize`, `f64`, `bool`,
+/// `char` and strings), in either order. Only the matching variant can be
+/// equal, so `Value::Int(1) == 1.0` is `false`; use [`Value::value_eq`] for
+/// numeric equality across variants.
+///
+/// ```
+/// use bytecode::values::Value;
+///
+/// assert_eq!(Value::Int(3), 3);
+/// assert_eq!(1.5, Value::Float(1.5));
+/// assert_eq!(Value::Bool(true), true);
+/// assert_eq!('c', Value::Char('c'));
+/// assert_ne!(Value::Int(1), 1.0);
+/// assert_ne!(Value::Char('a'), "a");
+/// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum Value {
     Int(isize),
     Float(f64),
     Bool(bool),
     Str(String),
+    /// Encoded as its 4-byte scalar value, so every `char` round-trips.
     Char(char),
+    /// Single-precision float kept at its native width, so `f32` values
+    /// round-trip as 4 bytes instead of being widened to `Float`.
+    /// `From<f32>` still widens to `Float`; build this variant explicitly.
+    Float32(f32),
+    /// Raw binary data, encoded as a LEB128 length followed by the bytes
+    /// with no UTF-8 validation.
+    Bytes(Vec<u8>),
+    /// Integer range `start..end`, or `start..=end` when `inclusive`.
+    /// Encoded as two `i64`s followed by a flag byte.
+    Range {
+        start: isize,
+        end: isize,
+        inclusive: bool,
+    },
+    /// An identifier, kept apart from string data: `Symbol("x")` never
+    /// equals `Str("x")`. Encoded like `Str` under its own tag.
+    Symbol(String),
+    /// Fixed-arity aggregate such as a pair or a multiple return value.
+    /// Encoded as a LEB128 element count followed by each element's own
+    /// encoding.
+    Tuple(Vec<Value>),
+}
+
+impl Value {
+    /// Returns the integer if this is an `Int`.
+    pub fn as_int(&self) -> Option<isize> {
+        match self {
+            Value::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns the float if this is a `Float`.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// Widens any numeric value to `f64`; `None` for non-numeric values.
+    ///
+    /// Unlike [`Value::as_float`] this converts `Int` and `Float32`. An
+    /// `Int` beyond 2^53 in magnitude is rounded to the nearest
+    /// representable `f64`, so the conversion can lose precision.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(*i as f64),
+            Value::Float(f) => Some(*f),
+            Value::Float32(f) => Some(*f as f64),
+            _ => None,
+        }
+    }
+
+    /// Whether this is a `Float` or `Float32` holding `NaN`.
+    pub fn is_nan(&self) -> bool {
+        match self {
+            Value::Float(f) => f.is_nan(),
+            Value::Float32(f) => f.is_nan(),
+            _ => false,
+        }
+    }
+
+    /// Whether this is a `Float` or `Float32` holding an infinity.
+    pub fn is_infinite(&self) -> bool {
+        match self {
+            Value::Float(f) => f.is_infinite(),
+            Value::Float32(f) => f.is_infinite(),
+            _ => false,
+        }
+    }
+
+    /// Whether this is a `Float` or `Float32` that is neither infinite nor
+    /// `NaN`. Other variants, including `Int`, give `false`.
+    pub fn is_finite(&self) -> bool {
+        match self {
+            Value::Float(f) => f.is_finite(),
+            Value::Float32(f) => f.is_finite(),
+            _ => false,
+        }
+    }
+
+    /// Returns the boolean if this is a `Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Borrows the string if this is a `Str`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the character if this is a `Char`.
+    pub fn as_char(&self) -> Option<char> {
+        match self {
+            Value::Char(c) => Some(*c),
+            _ => None,
+        }
+    }
+
+    /// Whether `v` lies in this `Range`. Always `false` for other values.
+    pub fn range_contains(&self, v: isize) -> bool {
+        match self {
+            Value::Range {
+                start,
+                end,
+                inclusive: true,
+            } => (*start..=*end).contains(&v),
+            Value::Range {
+                start,
+                end,
+                inclusive: false,
+            } => (*start..*end).contains(&v),
+            _ => false,
+        }
+    }
+
+    /// Approximate number of heap bytes owned by the value, for memory
+    /// budgeting. Scalars own none; strings and blobs report their capacity;
+    /// tuples report their element buffer plus what each element owns.
+    pub fn heap_size(&self) -> usize {
+        match self {
+            Value::Int(_)
+            | Value::Float(_)
+            | Value::Bool(_)
+            | Value::Char(_)
+            | Value::Float32(_)
+            | Value::Range { .. } => 0,
+            Value::Str(s) | Value::Symbol(s) => s.capacity(),
+            Value::Bytes(b) => b.capacity(),
+            Value::Tuple(items) => {
+                items.capacity() * std::mem::size_of::<Value>()
+                    + items.iter().map(Value::heap_size).sum::<usize>()
+            }
+        }
+    }
+
+    /// Element count of a `Tuple`, scalar-value count of a `Str`, or byte
+    /// count of `Bytes`; `None` for every other variant.
+    pub fn len(&self) -> Option<usize> {
+        match self {
+            Value::Tuple(items) => Some(items.len()),
+            Value::Str(s) => Some(s.chars().count()),
+            Value::Bytes(b) => Some(b.len()),
+            _ => None,
+        }
+    }
+
+    /// Whether [`Value::len`] is zero; `None` where it has no length.
+    pub fn is_empty(&self) -> Option<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// [`Value::len`] as an `Int`, failing with `ValueError::InvalidOperand`
+    /// for values that have no length.
+    pub fn len_value(&self) -> Result<Value, ValueError> {
+        self.len()
+            .map(|len| Value::Int(len as isize))
+            .ok_or(ValueError::InvalidOperand {
+                op: "length",
+                operand: Type::from(self),
+            })
+    }
+
+    /// Number of Unicode scalar values in a `Str`, as an `Int`.
+    pub fn str_len(&self) -> Result<Value, ValueError> {
+        match self {
+            Value::Str(s) => Ok(Value::Int(s.chars().count() as isize)),
+            _ => Err(ValueError::InvalidOperand {
+                op: "string length",
+                operand: Type::from(self),
+            }),
+        }
+    }
+
+    /// The `Char` at scalar-value position `index` of a `Str`.
+    pub fn str_char_at(&self, index: &Value) -> Result<Value, ValueError> {
+        let (Value::Str(s), Value::Int(i)) = (self, index) else {
+            return Err(ValueError::InvalidOperation {
+                op: "string index",
+                lhs: Type::from(self),
+                rhs: Type::from(index),
+            });
+        };
+
+        usize::try_from(*i)
+            .ok()
+            .and_then(|i| s.chars().nth(i))
+            .map(Value::Char)
+            .ok_or_else(|| ValueError::IndexOutOfBounds {
+                index: *i,
+                len: s.chars().count(),
+            })
+    }
+
+    /// Converts the value to `target` using the language's implicit
+    /// conversions: `Int` and `Float32` widen to `Float`, `Bool` and `Char`
+    /// become `Int`, an `Int` becomes a `Char` if it is a valid scalar value,
+    /// a `Str` becomes the `Symbol` of the same name, and anything becomes a
+    /// `Str` through `Display`.
+    ///
+    /// Any other pair returns `ValueError::InvalidConversion`.
+    pub fn coerce_to(&self, target: Type) -> Result<Value, ValueError> {
+        match (self, target) {
+            (value, target) if Type::from(value) == target => Ok(value.clone()),
+            (Value::Int(i), Type::Float) => Ok(Value::Float(*i as f64)),
+            (Value::Float32(f), Type::Float) => Ok(Value::Float(*f as f64)),
+            (Value::Bool(b), Type::Int) => Ok(Value::Int(*b as isize)),
+            (Value::Char(c), Type::Int) => Ok(Value::Int(*c as isize)),
+            (Value::Int(i), Type::Char) => u32::try_from(*i)
+                .ok()
+                .and_then(char::from_u32)
+                .map(Value::Char)
+                .ok_or(ValueError::InvalidChar { code_point: *i }),
+            (Value::Str(s), Type::Symbol) => Ok(Value::Symbol(s.clone())),
+            (value, Type::Str) => Ok(Value::Str(value.to_string())),
+            (value, target) => Err(ValueError::InvalidConversion {
+                from: Type::from(value),
+                to: target,
+            }),
+        }
+    }
+
+    /// Parses an integer literal. A leading `+` or `-` is allowed, as are
+    /// `_` separators between digits (`1_000`).
+    pub fn parse_int(s: &str) -> Result<Value, ValueError> {
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        if unsigned.starts_with('_') || unsigned.ends_with('_') {
+            return Err(ValueError::ParseError {
+                target: Type::Int,
+                input: s.to_string(),
+            });
+        }
+
+        let digits: String = s.chars().filter(|c| *c != '_').collect();
+        digits
+            .parse::<isize>()
+            .map(Value::Int)
+            .map_err(|_| ValueError::ParseError {
+                target: Type::Int,
+                input: s.to_string(),
+            })
+    }
+
+    /// Parses a float literal with the rules of `f64`'s `FromStr`.
+    pub fn parse_float(s: &str) -> Result<Value, ValueError> {
+        s.parse::<f64>()
+            .map(Value::Float)
+            .map_err(|_| ValueError::ParseError {
+                target: Type::Float,
+                input: s.to_string(),
+            })
+    }
+
+    /// Parses `true` or `false`.
+    pub fn parse_bool(s: &str) -> Result<Value, ValueError> {
+        s.parse::<bool>()
+            .map(Value::Bool)
+            .map_err(|_| ValueError::ParseError {
+                target: Type::Bool,
+                input: s.to_string(),
+            })
+    }
+
+    /// Equality as the language sees it: numerically equal `Int`, `Float`
+    /// and `Float32` values compare equal (`Int(1)` equals `Float(1.0)`),
+    /// tuples compare element-wise this way, and everything else is compared
+    /// structurally. `Int` and float operands are compared exactly, so
+    /// `Int(2^53 + 1)` does not equal `Float(2^53)` even though the `Int`
+    /// rounds to it as an `f64`.
+    ///
+    /// The derived `PartialEq` stays exact: values of different variants are
+    /// never equal there, which is what internal code such as round-trip
+    /// checks wants.
+    pub fn value_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Int(a), Value::Float(b)) | (Value::Float(b), Value::Int(a)) => {
+                cmp_int_float(*a, *b) == Some(Ordering::Equal)
+            }
+            (Value::Int(a), Value::Float32(b)) | (Value::Float32(b), Value::Int(a)) => {
+                cmp_int_float(*a, *b as f64) == Some(Ordering::Equal)
+            }
+            (Value::Tuple(a), Value::Tuple(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.value_eq(y))
+            }
+            (Value::Float(a), Value::Float32(b)) | (Value::Float32(b), Value::Float(a)) => {
+                *a == *b as f64
+            }
+            _ => self == other,
+        }
+    }
+
+    /// Same result as `==`, but rejects values of different types or
+    /// lengths before comparing contents, so mismatched strings and tuples
+    /// fail without a deep walk.
+    pub fn fast_eq(&self, other: &Value) -> bool {
+        if std::mem::discriminant(self) != std::mem::discriminant(other) {
+            return false;
+        }
+
+        match (self, other) {
+            (Value::Str(a), Value::Str(b)) | (Value::Symbol(a), Value::Symbol(b)) => {
+                a.len() == b.len() && a == b
+            }
+            (Value::Bytes(a), Value::Bytes(b)) => a.len() == b.len() && a == b,
+            (Value::Tuple(a), Value::Tuple(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.fast_eq(y))
+            }
+            _ => self == other,
+        }
+    }
+
+    /// `self <= other` as a `Bool`. Unordered operands (a `NaN`) give
+    /// `false`, so this is not the negation of `>`.
+    pub fn less_equal(&self, other: &Value) -> Result<Value, ValueError> {
+        let ordering = self.compare(other, "less equal")?;
+        Ok(Value::Bool(matches!(
+            ordering,
+            Some(Ordering::Less | Ordering::Equal)
+        )))
+    }
+
+    /// `self >= other` as a `Bool`. Unordered operands (a `NaN`) give
+    /// `false`, so this is not the negation of `<`.
+    pub fn greater_equal(&self, other: &Value) -> Result<Value, ValueError> {
+        let ordering = self.compare(other, "greater equal")?;
+        Ok(Value::Bool(matches!(
+            ordering,
+            Some(Ordering::Greater | Ordering::Equal)
+        )))
+    }
+
+    /// `|self - other| <= epsilon` as a `Bool`, for numeric-tolerant
+    /// equality. All three must be `Float` or `Float32`; a `NaN` anywhere
+    /// gives `false`.
+    pub fn approx_eq(&self, other: &Value, epsilon: &Value) -> Result<Value, ValueError> {
+        let op = "approx equal";
+        let float = |value: &Value| match value {
+            Value::Float(_) | Value::Float32(_) => value.as_f64(),
+            _ => None,
+        };
+
+        let Some(epsilon) = float(epsilon) else {
+            return Err(ValueError::InvalidOperand {
+                op,
+                operand: Type::from(epsilon),
+            });
+        };
+        let (Some(a), Some(b)) = (float(self), float(other)) else {
+            return Err(ValueError::InvalidOperation {
+                op,
+                lhs: Type::from(self),
+                rhs: Type::from(other),
+            });
+        };
+
+        Ok(Value::Bool((a - b).abs() <= epsilon))
+    }
+
+    /// Orders two comparable values, comparing mixed numeric types by value.
+    /// `Int` against a float is ordered exactly rather than through a lossy
+    /// `f64` cast.
+    fn compare(&self, other: &Value, op: &'static str) -> Result<Option<Ordering>, ValueError> {
+        let (lhs, rhs) = (Type::from(self), Type::from(other));
+        if !lhs.is_comparable_with(rhs) {
+            return Err(ValueError::InvalidOperation { op, lhs, rhs });
+        }
+
+        Ok(match (self, other) {
+            (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+            (Value::Int(a), Value::Float(_) | Value::Float32(_)) => {
+                other.as_f64().and_then(|b| cmp_int_float(*a, b))
+            }
+            (Value::Float(_) | Value::Float32(_), Value::Int(b)) => self
+                .as_f64()
+                .and_then(|a| cmp_int_float(*b, a))
+                .map(Ordering::reverse),
+            _ => match (self.as_f64(), other.as_f64()) {
+                (Some(a), Some(b)) => a.partial_cmp(&b),
+                _ => self.partial_cmp(other),
+            },
+        })
+    }
+
+    /// Raises `self` to the power `exponent`.
+    ///
+    /// `Int ^ Int` with a non-negative exponent stays `Int` and reports
+    /// `ValueError::IntOverflow` if the result does not fit; a negative
+    /// exponent promotes to `Float`. Float operands use `powf`.
+    ///
+    /// Bases `0`, `1` and `-1` never overflow, so any exponent works for
+    /// them, even one beyond `u32::MAX`.
+    pub fn pow(self, exponent: Value) -> Result<Value, ValueError> {
+        match (&self, &exponent) {
+            (Value::Int(base), Value::Int(exp)) if *exp < 0 => {
+                Ok(Value::Float((*base as f64).powf(*exp as f64)))
+            }
+            (Value::Int(base @ -1..=1), Value::Int(exp)) => Ok(Value::Int(match (*base, *exp) {
+                (_, 0) => 1,
+                (-1, exp) if exp % 2 == 0 => 1,
+                (base, _) => base,
+            })),
+            (Value::Int(base), Value::Int(exp)) => u32::try_from(*exp)
+                .ok()
+                .and_then(|exp| base.checked_pow(exp))
+                .map(Value::Int)
+                .ok_or(ValueError::IntOverflow { op: "power" }),
+            // `Int ^ Int` never reaches the integer case of `numeric_binary`.
+            _ => numeric_binary(self, exponent, "power", |_, _| None, f64::powf),
+        }
+    }
+
+    /// Adds two values, wrapping on `Int` overflow instead of erroring.
+    /// `Char` operands behave as with `+`: a shifted character has no
+    /// wrapped value, so leaving the scalar range is still an error.
+    pub fn wrapping_add(self, rhs: Value) -> Result<Value, ValueError> {
+        match (&self, &rhs) {
+            (Value::Char(_), Value::Int(_)) | (Value::Int(_), Value::Char(_)) => self + rhs,
+            _ => numeric_binary(
+                self,
+                rhs,
+                "add",
+                |a, b| Some(a.wrapping_add(b)),
+                |a, b| a + b,
+            ),
+        }
+    }
+
+    /// Subtracts two values, wrapping on `Int` overflow instead of erroring.
+    /// `Char` operands behave as with `-`.
+    pub fn wrapping_sub(self, rhs: Value) -> Result<Value, ValueError> {
+        match (&self, &rhs) {
+            (Value::Char(_), Value::Int(_) | Value::Char(_)) => self - rhs,
+            _ => numeric_binary(
+                self,
+                rhs,
+                "subtract",
+                |a, b| Some(a.wrapping_sub(b)),
+                |a, b| a - b,
+            ),
+        }
+    }
+
+    /// Multiplies two values, wrapping on `Int` overflow instead of erroring.
+    pub fn wrapping_mul(self, rhs: Value) -> Result<Value, ValueError> {
+        numeric_binary(
+            self,
+            rhs,
+            "multiply",
+            |a, b| Some(a.wrapping_mul(b)),
+            |a, b| a * b,
+        )
+    }
+}
+
+impl Display for Value {
+    /// Floats honor the formatter's precision (`{:.2}`), including inside
+    /// tuples; `NaN` and infinities print as `NaN` and `inf` either way.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(val) => write!(f, "{val}"),
+            Value::Float(val) => match f.precision() {
+                Some(precision) => write!(f, "{val:.precision$}"),
+                None => write!(f, "{val}"),
+            },
+            Value::Bool(val) => write!(f, "{val}"),
+            Value::Str(val) => write!(f, "{val}"),
+            Value::Char(val) => write!(f, "{val}"),
+            Value::Float32(val) => match f.precision() {
+                Some(precision) => write!(f, "{val:.precision$}"),
+                None => write!(f, "{val}"),
+            },
+            Value::Bytes(val) => write!(f, "b\"{}\"", val.escape_ascii()),
+            Value::Range {
+                start,
+                end,
+                inclusive,
+            } => write!(f, "{start}..{}{end}", if *inclusive { "=" } else { "" }),
+            Value::Symbol(val) => write!(f, "{val}"),
+            Value::Tuple(items) => {
+                write!(f, "(")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    // Quoted so a comma inside a string cannot pass for a
+                    // separator.
+                    match item {
+                        Value::Str(val) | Value::Symbol(val) => write!(f, "{val:?}")?,
+                        Value::Char(val) => write!(f, "{val:?}")?,
+                        _ => Display::fmt(item, f)?,
+                    }
+                }
+                if items.len() == 1 {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Applies a numeric binary operation, promoting mixed operands.
+///
+/// `Int` with `Int` stays `Int` and reports `ValueError::IntOverflow` when
+/// `int` returns `None`. `Float32` with `Float32` stays `Float32`; any other
+/// numeric mix is promoted to `Float`.
+fn numeric_binary(
+    lhs: Value,
+    rhs: Value,
+    op: &'static str,
+    int: impl Fn(isize, isize) -> Option<isize>,
+    float: impl Fn(f64, f64) -> f64,
+) -> Result<Value, ValueError> {
+    match (&lhs, &rhs) {
+        (Value::Int(a), Value::Int(b)) => int(*a, *b)
+            .map(Value::Int)
+            .ok_or(ValueError::IntOverflow { op }),
+        (Value::Float32(a), Value::Float32(b)) => {
+            Ok(Value::Float32(float(*a as f64, *b as f64) as f32))
+        }
+        _ => match (lhs.as_f64(), rhs.as_f64()) {
+            (Some(a), Some(b)) => Ok(Value::Float(float(a, b))),
+            _ => Err(ValueError::InvalidOperation {
+                op,
+                lhs: Type::from(&lhs),
+                rhs: Type::from(&rhs),
+            }),
+        },
+    }
+}
+
+/// Applies a binary operation defined only for `Int` operands.
+fn int_binary(
+    lhs: Value,
+    rhs: Value,
+    op: &'static str,
+    int: impl Fn(isize, isize) -> Result<isize, ValueError>,
+) -> Result<Value, ValueError> {
+    match (&lhs, &rhs) {
+        (Value::Int(a), Value::Int(b)) => int(*a, *b).map(Value::Int),
+        _ => Err(ValueError::InvalidOperation {
+            op,
+            lhs: Type::from(&lhs),
+            rhs: Type::from(&rhs),
+        }),
+    }
+}
+
+/// Converts a shift amount, rejecting negative amounts and amounts of
+/// `isize::BITS` or more.
+fn shift_amount(amount: isize) -> Result<u32, ValueError> {
+    u32::try_from(amount)
+        .ok()
+        .filter(|amount| *amount < isize::BITS)
+        .ok_or(ValueError::InvalidShift { amount })
+}
+
+/// Orders an `Int` against a float without rounding the `Int` to `f64`.
+/// Both sides are compared as `i128`, which holds every `isize` and the
+/// integral part of every float below `2^127` in magnitude.
+fn cmp_int_float(int: isize, float: f64) -> Option<Ordering> {
+    const LIMIT: f64 = i128::MAX as f64;
+    if float.is_nan() {
+        return None;
+    }
+    if float >= LIMIT {
+        return Some(Ordering::Less);
+    }
+    if float < -LIMIT {
+        return Some(Ordering::Greater);
+    }
+
+    let whole = float.trunc();
+    let fraction = 0.0f64.partial_cmp(&(float - whole))?;
+    Some((int as i128).cmp(&(whole as i128)).then(fraction))
+}
+
+/// Offsets a `Char` by an `Int`, failing if the result is not a Unicode
+/// scalar value.
+fn char_offset(
+    c: char,
+    offset: isize,
+    op: &'static str,
+    apply: impl Fn(isize, isize) -> Option<isize>,
+) -> Result<Value, ValueError> {
+    let code_point = apply(c as isize, offset).ok_or(ValueError::IntOverflow { op })?;
+    u32::try_from(code_point)
+        .ok()
+        .and_then(char::from_u32)
+        .map(Value::Char)
+        .ok_or(ValueError::InvalidChar { code_point })
+}
+
+impl Add for Value {
+    type Output = Result<Value, ValueError>;
+
+    /// `Char + Int` and `Int + Char` shift the character by the integer.
+    fn add(self, rhs: Self) -> Self::Output {
+        match (&self, &rhs) {
+            (Value::Char(c), Value::Int(i)) | (Value::Int(i), Value::Char(c)) => {
+                char_offset(*c, *i, "add", isize::checked_add)
+            }
+            _ => numeric_binary(self, rhs, "add", isize::checked_add, |a, b| a + b),
+        }
+    }
+}
+
+impl Sub for Value {
+    type Output = Result<Value, ValueError>;
+
+    /// `Char - Int` shifts the character back; `Char - Char` is the `Int`
+    /// distance between the two scalar values.
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (&self, &rhs) {
+            (Value::Char(c), Value::Int(i)) => char_offset(*c, *i, "subtract", isize::checked_sub),
+            (Value::Char(a), Value::Char(b)) => Ok(Value::Int(*a as isize - *b as isize)),
+            _ => numeric_binary(self, rhs, "subtract", isize::checked_sub, |a, b| a - b),
+        }
+    }
+}
+
+impl Mul for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        numeric_binary(self, rhs, "multiply", isize::checked_mul, |a, b| a * b)
+    }
+}
+
+impl BitAnd for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        int_binary(self, rhs, "bitwise and", |a, b| Ok(a & b))
+    }
+}
+
+impl BitOr for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        int_binary(self, rhs, "bitwise or", |a, b| Ok(a | b))
+    }
+}
+
+impl BitXor for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        int_binary(self, rhs, "bitwise xor", |a, b| Ok(a ^ b))
+    }
+}
+
+impl Shl for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        int_binary(self, rhs, "shift left", |a, b| Ok(a << shift_amount(b)?))
+    }
+}
+
+impl Shr for Value {
+    type Output = Result<Value, ValueError>;
+
+    /// Arithmetic shift: the sign bit is preserved.
+    fn shr(self, rhs: Self) -> Self::Output {
+        int_binary(self, rhs, "shift right", |a, b| Ok(a >> shift_amount(b)?))
+    }
+}
+
+// Compares a `Value` with a host primitive in both directions; the examples
+// live on `Value` so rustdoc renders and tests them.
+macro_rules! impl_partial_eq_primitive {
+    ($($t:ty => $variant:ident), *) => {
+        $(impl PartialEq<$t> for Value {
+            fn eq(&self, other: &$t) -> bool {
+                matches!(self, Value::$variant(val) if val == other)
+            }
+        }
+
+        impl PartialEq<Value> for $t {
+            fn eq(&self, other: &Value) -> bool {
+                other == self
+            }
+        })*
+    };
+}
+impl_partial_eq_primitive!(isize => Int, f64 => Float, bool => Bool, char => Char);
+
+impl PartialEq<str> for Value {
+    /// ```
+    /// use bytecode::values::Value;
+    ///
+    /// assert!(Value::Str(String::from("hi")) == *"hi");
+    /// ```
+    fn eq(&self, other: &str) -> bool {
+        matches!(self, Value::Str(val) if val == other)
+    }
+}
+
+impl PartialEq<Value> for str {
+    fn eq(&self, other: &Value) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<&str> for Value {
+    /// ```
+    /// use bytecode::values::Value;
+    ///
+    /// assert_eq!(Value::Str(String::from("hi")), "hi");
+    /// ```
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<Value> for &str {
+    fn eq(&self, other: &Value) -> bool {
+        other == *self
+    }
+}
+
+/// A [`Value`] under a total order, for use as a `BTreeSet` or `BTreeMap`
+/// key. `Value` itself is only `PartialOrd` because of `NaN`.
+///
+/// Values of different types order by their type tag. Floats within a type
+/// use `total_cmp`: a positive `NaN` sorts after infinity, a negative one
+/// before negative infinity, and `-0.0` sorts before `0.0`. Equality follows
+/// the same order, so `NaN` equals itself here and `-0.0` does not equal
+/// `0.0`.
+#[derive(Debug, Clone)]
+pub struct OrdValue(pub Value);
+
+impl Ord for OrdValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        total_cmp(&self.0, &other.0)
+    }
+}
+
+/// The order behind [`OrdValue`]; tuples compare element by element.
+fn total_cmp(lhs: &Value, rhs: &Value) -> Ordering {
+    match (lhs, rhs) {
+        (Value::Int(a), Value::Int(b)) => a.cmp(b),
+        (Value::Float(a), Value::Float(b)) => a.total_cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Str(a), Value::Str(b)) => a.cmp(b),
+        (Value::Char(a), Value::Char(b)) => a.cmp(b),
+        (Value::Float32(a), Value::Float32(b)) => a.total_cmp(b),
+        (Value::Bytes(a), Value::Bytes(b)) => a.cmp(b),
+        (
+            Value::Range {
+                start: s1,
+                end: e1,
+                inclusive: i1,
+            },
+            Value::Range {
+                start: s2,
+                end: e2,
+                inclusive: i2,
+            },
+        ) => (s1, e1, i1).cmp(&(s2, e2, i2)),
+        (Value::Symbol(a), Value::Symbol(b)) => a.cmp(b),
+        (Value::Tuple(a), Value::Tuple(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(x, y)| total_cmp(x, y))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (a, b) => (Type::from(a) as u8).cmp(&(Type::from(b) as u8)),
+    }
+}
+
+impl PartialOrd for OrdValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for OrdValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrdValue {}
+
+impl From<Value> for OrdValue {
+    fn from(value: Value) -> Self {
+        OrdValue(value)
+    }
+}
+
+/// Builds a [`Value`] from a literal using its `From` impl.
+///
+/// ```
+/// use bytecode::value;
+/// use bytecode::values::Value;
+///
+/// assert_eq!(value!(42), Value::Int(42));
+/// assert_eq!(value!(2.5), Value::Float(2.5));
+/// assert_eq!(value!("hi"), Value::Str(String::from("hi")));
+/// assert_eq!(value!(true), Value::Bool(true));
+/// assert_eq!(value!('c'), Value::Char('c'));
+/// ```
+#[macro_export]
+macro_rules! value {
+    ($literal:expr) => {
+        $crate::values::Value::from($literal)
+    };
 }
 
 macro_rules! impl_from_int {
@@ -73,6 +916,25 @@ impl TryFrom<Value> for isize {
     }
 }
 
+/// Narrower integer extraction: the value must be an `Int`, and its `isize`
+/// must fit the target or `ValueError::IntOutOfRange` is returned.
+macro_rules! impl_try_from_value_int {
+    ($($t:ty), *) => {
+        $(impl TryFrom<Value> for $t {
+            type Error = ValueError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                let i = isize::try_from(value)?;
+                <$t>::try_from(i).map_err(|_| ValueError::IntOutOfRange {
+                    value: i,
+                    target: stringify!($t),
+                })
+            }
+        })*
+    };
+}
+impl_try_from_value_int!(i32, u32, u8, usize);
+
 impl TryFrom<Value> for f64 {
     type Error = ValueError;
 
@@ -87,87 +949,494 @@ impl TryFrom<Value> for f64 {
     }
 }
 
-impl From<Value> for Vec<u8> {
-    fn from(value: Value) -> Self {
-        let mut buffer = Vec::new();
-        buffer.push(Type::from(&value) as u8);
+impl TryFrom<Value> for f32 {
+    type Error = ValueError;
 
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
         match value {
-            Value::Int(val) => buffer.extend_from_slice(&(val as i64).to_le_bytes()),
-            Value::Float(val) => buffer.extend_from_slice(&val.to_le_bytes()),
-            Value::Bool(val) => buffer.push(val as u8),
-            Value::Str(val) => {
+            Value::Float32(f) => Ok(f),
+            _ => Err(ValueError::InvalidConversion {
+                from: Type::from(&value),
+                to: Type::Float32,
+            }),
+        }
+    }
+}
+
+impl Value {
+    /// Boxes the inner Rust value for reflective host code: `isize`, `f64`,
+    /// `bool`, `String`, `char`, `f32` or `Vec<u8>`. Variants without a
+    /// single primitive inside (`Range`, `Symbol`, `Tuple`) box the `Value`
+    /// itself.
+    pub fn into_any(self) -> Box<dyn Any> {
+        match self {
+            Value::Int(i) => Box::new(i),
+            Value::Float(f) => Box::new(f),
+            Value::Bool(b) => Box::new(b),
+            Value::Str(s) => Box::new(s),
+            Value::Char(c) => Box::new(c),
+            Value::Float32(f) => Box::new(f),
+            Value::Bytes(b) => Box::new(b),
+            value @ (Value::Range { .. } | Value::Symbol(_) | Value::Tuple(_)) => Box::new(value),
+        }
+    }
+
+    /// The reverse of [`Value::into_any`]: rebuilds a value from any of the
+    /// types it produces, or `None` for anything else.
+    pub fn from_any(any: &dyn Any) -> Option<Value> {
+        if let Some(i) = any.downcast_ref::<isize>() {
+            Some(Value::Int(*i))
+        } else if let Some(f) = any.downcast_ref::<f64>() {
+            Some(Value::Float(*f))
+        } else if let Some(b) = any.downcast_ref::<bool>() {
+            Some(Value::Bool(*b))
+        } else if let Some(s) = any.downcast_ref::<String>() {
+            Some(Value::Str(s.clone()))
+        } else if let Some(c) = any.downcast_ref::<char>() {
+            Some(Value::Char(*c))
+        } else if let Some(f) = any.downcast_ref::<f32>() {
+            Some(Value::Float32(*f))
+        } else if let Some(b) = any.downcast_ref::<Vec<u8>>() {
+            Some(Value::Bytes(b.clone()))
+        } else {
+            any.downcast_ref::<Value>().cloned()
+        }
+    }
+}
+
+impl Value {
+    /// Longest `Str` payload, in bytes, that fits the `u32` length prefix of
+    /// the byte format.
+    pub const MAX_STR_LEN: usize = u32::MAX as usize;
+
+    /// Largest length prefix accepted by the decoders that take no explicit
+    /// limit. See [`Value::decode_with_limit`].
+    pub const DEFAULT_DECODE_LIMIT: usize = Self::MAX_STR_LEN;
+
+    /// Deepest tuple nesting the decoders accept, so a forged buffer cannot
+    /// exhaust the stack through recursion.
+    pub const MAX_NESTING_DEPTH: usize = 64;
+
+    /// Rewrites floats to a canonical form so semantically equal values
+    /// encode to identical bytes: every `NaN` becomes the standard quiet
+    /// `NaN` and `-0.0` becomes `0.0`.
+    ///
+    /// Encoding is bit-preserving by default; call this first when
+    /// reproducible output matters.
+    pub fn canonicalize(&mut self) {
+        match self {
+            Value::Float(f) if f.is_nan() => *f = f64::NAN,
+            Value::Float(f) if *f == 0.0 => *f = 0.0,
+            Value::Float32(f) if f.is_nan() => *f = f32::NAN,
+            Value::Float32(f) if *f == 0.0 => *f = 0.0,
+            Value::Tuple(items) => items.iter_mut().for_each(Value::canonicalize),
+            _ => {}
+        }
+    }
+
+    /// Rebuilds the value bottom-up, applying `f` to every element of a
+    /// `Tuple` before the tuple itself, and to scalars directly.
+    pub fn map_deep<F: FnMut(Value) -> Value>(self, mut f: F) -> Value {
+        self.map_deep_with(&mut f)
+    }
+
+    fn map_deep_with<F: FnMut(Value) -> Value>(self, f: &mut F) -> Value {
+        let value = match self {
+            Value::Tuple(items) => Value::Tuple(
+                items
+                    .into_iter()
+                    .map(|item| item.map_deep_with(f))
+                    .collect(),
+            ),
+            value => value,
+        };
+        f(value)
+    }
+
+    /// Encodes the value, failing with `ValueError::StringTooLong` when a
+    /// `Str` is longer than [`Value::MAX_STR_LEN`] bytes.
+    ///
+    /// The infallible `From<Value> for Vec<u8>` would truncate the length
+    /// prefix of such a string instead.
+    pub fn try_encode(&self) -> Result<Vec<u8>, ValueError> {
+        self.check_encodable()?;
+        Ok(self.encode())
+    }
+
+    fn check_encodable(&self) -> Result<(), ValueError> {
+        match self {
+            Value::Str(val) | Value::Symbol(val) if val.len() > Self::MAX_STR_LEN => {
+                Err(ValueError::StringTooLong { len: val.len() })
+            }
+            Value::Tuple(items) => items.iter().try_for_each(Value::check_encodable),
+            _ => Ok(()),
+        }
+    }
+
+    /// Renders the value's serialized bytes as annotated hex, one field per
+    /// line, for inspecting the byte format.
+    ///
+    /// ```
+    /// use bytecode::values::Value;
+    ///
+    /// assert_eq!(
+    ///     Value::Int(42).hexdump(),
+    ///     "20 | Int tag\n2a 00 00 00 00 00 00 00 | 42"
+    /// );
+    /// ```
+    pub fn hexdump(&self) -> String {
+        let bytes = self.encode();
+        let (tag, payload) = bytes.split_at(1);
+        let mut lines = vec![format!("{} | {} tag", hex(tag), Type::from(self))];
+
+        let data = match self {
+            Value::Str(_) | Value::Symbol(_) | Value::Bytes(_) => {
+                let prefix_len = match self {
+                    Value::Str(_) | Value::Symbol(_) => 4,
+                    _ => leb128::read_unsigned(payload).map_or(0, |(_, len)| len),
+                };
+                let (prefix, data) = payload.split_at(prefix_len);
+                lines.push(format!("{} | length {}", hex(prefix), data.len()));
+                data
+            }
+            Value::Tuple(items) => {
+                let prefix_len = leb128::read_unsigned(payload).map_or(0, |(_, len)| len);
+                let (prefix, data) = payload.split_at(prefix_len);
+                lines.push(format!("{} | {} elements", hex(prefix), items.len()));
+                data
+            }
+            _ => payload,
+        };
+
+        if !data.is_empty() {
+            let annotation = match self {
+                Value::Int(val) => val.to_string(),
+                Value::Float(val) => val.to_string(),
+                Value::Bool(val) => val.to_string(),
+                Value::Str(val) => format!("{val:?}"),
+                Value::Symbol(val) => val.clone(),
+                Value::Tuple(_) => self.to_string(),
+                Value::Char(val) => format!("{val:?} (U+{:04X})", *val as u32),
+                Value::Float32(val) => val.to_string(),
+                Value::Bytes(val) => format!("{} raw bytes", val.len()),
+                Value::Range { .. } => self.to_string(),
+            };
+            lines.push(format!("{} | {}", hex(data), annotation));
+        }
+
+        lines.join("\n")
+    }
+
+    /// 64-bit FNV-1a hash of the value's default encoding. Unlike `Hash`
+    /// with `RandomState`, the result is the same in every process, so it
+    /// can key caches that outlive a run.
+    ///
+    /// Floats hash by bit pattern; call [`Value::canonicalize`] first if
+    /// `-0.0` and `0.0`, or different `NaN`s, should collide.
+    pub fn stable_hash(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        self.encode().iter().fold(OFFSET_BASIS, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(PRIME)
+        })
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.encode_as(Encoding::default())
+    }
+
+    /// Encodes the value with its multi-byte fields in the given byte order.
+    /// `From<Value> for Vec<u8>` always uses little-endian.
+    pub fn encode_with(&self, order: ByteOrder) -> Vec<u8> {
+        self.encode_as(Encoding {
+            order,
+            ..Encoding::default()
+        })
+    }
+
+    /// Encodes the value with the given format options.
+    pub fn encode_as(&self, encoding: Encoding) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.write_as(&mut buffer, encoding);
+        buffer
+    }
+
+    /// Appends the value's encoding to `out`, reusing its allocation when
+    /// serializing many values into one buffer.
+    pub fn write_to(&self, out: &mut Vec<u8>) {
+        self.write_as(out, Encoding::default());
+    }
+
+    fn write_as(&self, buffer: &mut Vec<u8>, encoding: Encoding) {
+        let order = encoding.order;
+        buffer.push(Type::from(self) as u8);
+
+        match self {
+            Value::Int(val) => match encoding.ints {
+                IntEncoding::Fixed => buffer.extend_from_slice(&(*val as i64).to_bytes(order)),
+                IntEncoding::Leb128 => leb128::write_signed(buffer, *val as i64),
+            },
+            Value::Float(val) => buffer.extend_from_slice(&val.to_bytes(order)),
+            Value::Bool(val) => buffer.push(*val as u8),
+            Value::Str(val) | Value::Symbol(val) => {
                 let bytes = val.as_bytes();
                 let len = bytes.len() as u32;
-                buffer.extend_from_slice(&len.to_le_bytes());
+                buffer.extend_from_slice(&len.to_bytes(order));
                 buffer.extend_from_slice(bytes);
             }
-            Value::Char(val) => buffer.push(val as u8),
+            Value::Char(val) => buffer.extend_from_slice(&(*val as u32).to_bytes(order)),
+            Value::Float32(val) => buffer.extend_from_slice(&val.to_bytes(order)),
+            Value::Bytes(val) => {
+                leb128::write_unsigned(buffer, val.len() as u64);
+                buffer.extend_from_slice(val);
+            }
+            Value::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                buffer.extend_from_slice(&(*start as i64).to_bytes(order));
+                buffer.extend_from_slice(&(*end as i64).to_bytes(order));
+                buffer.push(*inclusive as u8);
+            }
+            Value::Tuple(items) => {
+                leb128::write_unsigned(buffer, items.len() as u64);
+                for item in items {
+                    item.write_as(buffer, encoding);
+                }
+            }
         }
+    }
+}
 
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl From<Value> for Vec<u8> {
+    fn from(value: Value) -> Self {
+        debug_assert!(
+            value.check_encodable().is_ok(),
+            "value cannot be encoded faithfully, use Value::try_encode"
+        );
+        let mut buffer = Vec::new();
+        value.write_to(&mut buffer);
         buffer
     }
 }
 
-impl TryFrom<Vec<u8>> for Value {
-    type Error = ValueError;
+impl Value {
+    /// Decodes exactly one value from the start of `bytes`, returning it with
+    /// the number of bytes consumed. Any bytes after the value are left for
+    /// the caller, so concatenated values can be decoded one after another.
+    pub fn decode_prefix(bytes: &[u8]) -> Result<(Value, usize), ValueError> {
+        Value::decode_prefix_as(bytes, Encoding::default(), Self::DEFAULT_DECODE_LIMIT, 0)
+    }
 
-    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
-        let Some(tag) = value.first() else {
-            return Err(ValueError::NoTag);
-        };
-        let data_len = value.len() - 1;
+    /// Decodes a buffer holding exactly one value whose multi-byte fields
+    /// are in the given byte order.
+    pub fn decode_with(bytes: &[u8], order: ByteOrder) -> Result<Value, ValueError> {
+        Value::decode_as(
+            bytes,
+            Encoding {
+                order,
+                ..Encoding::default()
+            },
+        )
+    }
 
-        match Type::try_from(tag.to_owned())? {
-            Type::Int => {
-                if data_len != 8 {
-                    return Err(ValueError::IncompatibleSize);
-                }
+    /// Decodes a buffer holding exactly one value written with the given
+    /// format options.
+    pub fn decode_as(bytes: &[u8], encoding: Encoding) -> Result<Value, ValueError> {
+        Value::decode_exact(bytes, encoding, Self::DEFAULT_DECODE_LIMIT)
+    }
 
-                let mut slice = [0u8; 8];
-                slice.copy_from_slice(&value[1..]);
-                Ok(Value::Int(i64::from_le_bytes(slice) as isize))
-            }
-            Type::Float => {
-                if data_len != 8 {
-                    return Err(ValueError::IncompatibleSize);
-                }
+    /// Decodes a buffer holding exactly one value, rejecting any length
+    /// prefix above `max_len` before its payload is read. Use this for
+    /// buffers from untrusted sources.
+    pub fn decode_with_limit(bytes: &[u8], max_len: usize) -> Result<Value, ValueError> {
+        Value::decode_exact(bytes, Encoding::default(), max_len)
+    }
 
-                let mut slice = [0u8; 8];
-                slice.copy_from_slice(&value[1..]);
-                Ok(Value::Float(f64::from_le_bytes(slice)))
+    /// Decodes a buffer holding one value followed by fewer than `align`
+    /// zero bytes of padding, as written by producers that pad to a fixed
+    /// frame size. Non-zero or excess trailing bytes are an
+    /// `IncompatibleSize` error; an `align` of 0 or 1 allows no padding.
+    pub fn decode_aligned(bytes: &[u8], align: usize) -> Result<Value, ValueError> {
+        let (decoded, consumed) = Value::decode_prefix(bytes)?;
+        let padding = &bytes[consumed..];
+        if padding.len() >= align.max(1) || padding.iter().any(|&byte| byte != 0) {
+            return Err(ValueError::IncompatibleSize);
+        }
+
+        Ok(decoded)
+    }
+
+    fn decode_exact(bytes: &[u8], encoding: Encoding, max_len: usize) -> Result<Value, ValueError> {
+        let (decoded, consumed) = Value::decode_prefix_as(bytes, encoding, max_len, 0)?;
+        if consumed != bytes.len() {
+            return Err(ValueError::IncompatibleSize);
+        }
+
+        Ok(decoded)
+    }
+
+    fn decode_prefix_as(
+        bytes: &[u8],
+        encoding: Encoding,
+        max_len: usize,
+        depth: usize,
+    ) -> Result<(Value, usize), ValueError> {
+        let order = encoding.order;
+        let ty = Type::peek(bytes)?;
+        let data = &bytes[1..];
+
+        let (value, data_len) = match ty {
+            Type::Int => match encoding.ints {
+                IntEncoding::Fixed => {
+                    let slice = fixed_width(data)?;
+                    (Value::Int(i64::from_bytes(slice, order) as isize), 8)
+                }
+                IntEncoding::Leb128 => {
+                    let Some((val, len)) = leb128::read_signed(data) else {
+                        return Err(ValueError::IncompatibleSize);
+                    };
+                    (Value::Int(val as isize), len)
+                }
+            },
+            Type::Float => {
+                let slice = fixed_width(data)?;
+                (Value::Float(f64::from_bytes(slice, order)), 8)
             }
             Type::Bool => {
-                if data_len != 1 {
+                let [byte] = fixed_width::<1>(data)?;
+                (Value::Bool(byte != 0), 1)
+            }
+            ty @ (Type::Str | Type::Symbol) => {
+                let len = u32::from_bytes(fixed_width(data)?, order);
+                check_length(u64::from(len), max_len)?;
+                let end = str_payload_end(u64::from(len));
+                let Some(payload) = end.and_then(|end| data.get(4..end)) else {
                     return Err(ValueError::IncompatibleSize);
-                }
+                };
 
-                Ok(Value::Bool(value[1] != 0))
+                let str =
+                    String::from_utf8(payload.to_vec()).map_err(|_| ValueError::InvalidUtf8)?;
+                let value = match ty {
+                    Type::Symbol => Value::Symbol(str),
+                    _ => Value::Str(str),
+                };
+                (value, 4 + payload.len())
+            }
+            Type::Char => {
+                let code_point = u32::from_bytes(fixed_width(data)?, order);
+                let ch = char::from_u32(code_point).ok_or(ValueError::InvalidChar {
+                    code_point: code_point as isize,
+                })?;
+                (Value::Char(ch), 4)
+            }
+            Type::Float32 => {
+                let slice = fixed_width(data)?;
+                (Value::Float32(f32::from_bytes(slice, order)), 4)
             }
-            Type::Str => {
-                if data_len < 4 {
+            Type::Bytes => {
+                let Some((len, prefix_len)) = leb128::read_unsigned(data) else {
                     return Err(ValueError::IncompatibleSize);
-                }
-
-                let mut len_slice = [0u8; 4];
-                len_slice.copy_from_slice(&value[1..=4]);
-
-                let len = u32::from_le_bytes(len_slice) as usize;
-                if data_len != len + 4 {
+                };
+                check_length(len, max_len)?;
+                let payload = usize::try_from(len)
+                    .ok()
+                    .and_then(|len| data[prefix_len..].get(..len));
+                let Some(payload) = payload else {
                     return Err(ValueError::IncompatibleSize);
-                }
+                };
 
-                let str = String::from_utf8_lossy(&value[5..]);
-                Ok(Value::Str(str.to_string()))
+                (Value::Bytes(payload.to_vec()), prefix_len + payload.len())
             }
-            Type::Char => {
-                if data_len != 1 {
+            Type::Range => {
+                let start = i64::from_bytes(fixed_width(data)?, order);
+                let end = i64::from_bytes(fixed_width(data.get(8..).unwrap_or_default())?, order);
+                let [inclusive] = fixed_width(data.get(16..).unwrap_or_default())?;
+                let range = Value::Range {
+                    start: start as isize,
+                    end: end as isize,
+                    inclusive: inclusive != 0,
+                };
+                (range, 17)
+            }
+            Type::Tuple => {
+                let Some((count, mut offset)) = leb128::read_unsigned(data) else {
                     return Err(ValueError::IncompatibleSize);
+                };
+                check_length(count, max_len)?;
+                if depth >= Self::MAX_NESTING_DEPTH {
+                    return Err(ValueError::NestingTooDeep {
+                        max: Self::MAX_NESTING_DEPTH,
+                    });
                 }
 
-                Ok(Value::Char(value[1] as char))
+                // Every element takes at least one byte, so the count alone
+                // never drives the allocation past the input size.
+                let mut items = Vec::new();
+                for _ in 0..count {
+                    let (item, consumed) =
+                        Value::decode_prefix_as(&data[offset..], encoding, max_len, depth + 1)?;
+                    items.push(item);
+                    offset += consumed;
+                }
+                (Value::Tuple(items), offset)
             }
-        }
+        };
+
+        Ok((value, 1 + data_len))
+    }
+}
+
+/// Rejects a decoded length prefix that claims more than `max` bytes.
+fn check_length(claimed: u64, max: usize) -> Result<(), ValueError> {
+    if claimed > max as u64 {
+        return Err(ValueError::LengthLimitExceeded { claimed, max });
+    }
+
+    Ok(())
+}
+
+/// End offset of a `Str` payload of `len` bytes after its 4-byte length
+/// prefix, or `None` if it does not fit `usize`. Checked so a forged length
+/// near `u32::MAX` cannot wrap on 32-bit targets and slip past the bounds
+/// check.
+fn str_payload_end(len: u64) -> Option<usize> {
+    usize::try_from(len).ok()?.checked_add(4)
+}
+
+/// Reads the first `N` bytes of a fixed-width payload.
+fn fixed_width<const N: usize>(data: &[u8]) -> Result<[u8; N], ValueError> {
+    data.get(..N)
+        .and_then(|slice| slice.try_into().ok())
+        .ok_or(ValueError::IncompatibleSize)
+}
+
+impl TryFrom<&[u8]> for Value {
+    type Error = ValueError;
+
+    /// Decodes a buffer holding exactly one value; trailing bytes are an
+    /// `IncompatibleSize` error. Use [`Value::decode_prefix`] to decode
+    /// concatenated values.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Value::decode_with(value, ByteOrder::default())
+    }
+}
+
+impl TryFrom<Vec<u8>> for Value {
+    type Error = ValueError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Value::try_from(value.as_slice())
     }
 }
 
@@ -179,6 +1448,55 @@ pub enum ValueError {
     NoTag,
     #[error("Value size is incompatible with the received buffer size")]
     IncompatibleSize,
-    #[error(transparent)]
+    #[error("String payload is not valid UTF-8")]
+    InvalidUtf8,
+    #[error("String of {len} bytes is longer than the encodable maximum")]
+    StringTooLong { len: usize },
+    #[error("Length prefix of {claimed} bytes exceeds the limit of {max}")]
+    LengthLimitExceeded { claimed: u64, max: usize },
+    #[error("Value nests deeper than the limit of {max}")]
+    NestingTooDeep { max: usize },
+    #[error("Integer {value} does not fit in {target}")]
+    IntOutOfRange { value: isize, target: &'static str },
+    #[error("Integer overflow in {op}")]
+    IntOverflow { op: &'static str },
+    #[error("Invalid operation {op} between {lhs} and {rhs}")]
+    InvalidOperation {
+        op: &'static str,
+        lhs: Type,
+        rhs: Type,
+    },
+    #[error("Invalid operand {operand} for {op}")]
+    InvalidOperand { op: &'static str, operand: Type },
+    #[error("Index {index} is out of bounds for length {len}")]
+    IndexOutOfBounds { index: isize, len: usize },
+    #[error("{code_point:#x} is not a valid character")]
+    InvalidChar { code_point: isize },
+    #[error("Cannot parse {input:?} as {target}")]
+    ParseError { target: Type, input: String },
+    #[error("Invalid shift amount {amount}")]
+    InvalidShift { amount: isize },
+    #[error("Buffer has an unreadable type tag: {0}")]
     Type(#[from] TypeError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::str_payload_end;
+
+    #[test]
+    fn test_str_payload_end_rejects_overflow() {
+        let max = usize::MAX as u64;
+        for len in max - 3..=max {
+            assert_eq!(str_payload_end(len), None, "len {len}");
+        }
+        assert_eq!(str_payload_end(max - 4), Some(usize::MAX));
+    }
+
+    #[test]
+    fn test_str_payload_end_of_max_prefix() {
+        let expected = usize::try_from(u64::from(u32::MAX) + 4).ok();
+        assert_eq!(str_payload_end(u64::from(u32::MAX)), expected);
+        assert_eq!(str_payload_end(0), Some(4));
+    }
+}
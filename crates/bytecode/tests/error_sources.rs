@@ -0,0 +1,36 @@
+use std::error::Error;
+
+use bytecode::opcode::OpCodeError;
+use bytecode::types::TypeError;
+use bytecode::values::{Value, ValueError};
+
+fn assert_error<E: Error + Send + Sync + 'static>() {}
+
+#[test]
+fn test_every_error_implements_std_error() {
+    assert_error::<OpCodeError>();
+    assert_error::<TypeError>();
+    assert_error::<ValueError>();
+}
+
+#[test]
+fn test_value_error_chains_to_type_error() {
+    let Err(error) = Value::try_from(vec![0xFF]) else {
+        panic!("Invalid type tag should return error");
+    };
+
+    let source = error
+        .source()
+        .and_then(|source| source.downcast_ref::<TypeError>());
+    match source {
+        Some(TypeError::InvalidType(0xFF)) => {} // Expected
+        other => panic!("Expected TypeError source, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_leaf_errors_have_no_source() {
+    assert!(ValueError::NoTag.source().is_none());
+    assert!(TypeError::InvalidType(0).source().is_none());
+    assert!(OpCodeError::InvalidOpCode(0).source().is_none());
+}
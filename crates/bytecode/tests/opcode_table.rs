@@ -0,0 +1,94 @@
+use bytecode::opcode::OpCode;
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_every_opcode_roundtrips_through_u8() {
+    for &op in OpCode::all() {
+        let decoded = OpCode::try_from(op as u8).expect("Listed opcode should decode");
+        assert_eq!(decoded, op);
+    }
+}
+
+#[test]
+fn test_all_lists_every_decodable_opcode() {
+    let decodable: Vec<OpCode> = (0..=u8::MAX)
+        .filter_map(|byte| OpCode::try_from(byte).ok())
+        .collect();
+    assert_eq!(
+        decodable,
+        OpCode::all(),
+        "OpCode::all() is out of sync with TryFrom<u8>"
+    );
+}
+
+#[test]
+fn test_every_opcode_has_metadata() {
+    for &op in OpCode::all() {
+        assert!(!op.name().is_empty(), "{:?} has an empty name", op);
+        assert!(op.operand_bytes() <= 1, "{:?} has an unexpected width", op);
+    }
+}
+
+#[test]
+fn test_opcode_names_are_unique() {
+    let mut names: Vec<&str> = OpCode::all().iter().map(|op| op.name()).collect();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), OpCode::all().len());
+}
+
+#[test]
+fn test_is_binary_arithmetic() {
+    let expected = [
+        OpCode::Add,
+        OpCode::Subtract,
+        OpCode::Multiply,
+        OpCode::Divide,
+        OpCode::BitAnd,
+        OpCode::BitOr,
+        OpCode::BitXor,
+        OpCode::ShiftLeft,
+        OpCode::ShiftRight,
+        OpCode::Power,
+    ];
+    for &op in OpCode::all() {
+        assert_eq!(
+            op.is_binary_arithmetic(),
+            expected.contains(&op),
+            "{:?}",
+            op
+        );
+    }
+}
+
+#[test]
+fn test_is_comparison() {
+    let expected = [OpCode::LessEqual, OpCode::GreaterEqual];
+    for &op in OpCode::all() {
+        assert_eq!(op.is_comparison(), expected.contains(&op), "{:?}", op);
+    }
+}
+
+#[test]
+fn test_is_terminator() {
+    let expected = [OpCode::Return, OpCode::ReturnN, OpCode::Halt];
+    for &op in OpCode::all() {
+        assert_eq!(op.is_terminator(), expected.contains(&op), "{:?}", op);
+    }
+}
+
+#[test]
+fn test_groups_are_disjoint() {
+    for &op in OpCode::all() {
+        let groups = [
+            op.is_binary_arithmetic(),
+            op.is_comparison(),
+            op.is_terminator(),
+        ];
+        assert!(
+            groups.iter().filter(|&&g| g).count() <= 1,
+            "{:?} is in more than one group",
+            op
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use bytecode::types::Type;
+
+#[test]
+fn test_is_numeric_table() {
+    for &ty in Type::all() {
+        let expected = matches!(ty, Type::Int | Type::Float | Type::Float32);
+        assert_eq!(ty.is_numeric(), expected, "is_numeric for {}", ty);
+    }
+}
+
+#[test]
+fn test_numeric_types_are_mutually_comparable() {
+    for a in [Type::Int, Type::Float, Type::Float32] {
+        for b in [Type::Int, Type::Float, Type::Float32] {
+            assert!(a.is_comparable_with(b), "{} should compare with {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_non_numeric_types_compare_only_with_themselves() {
+    for ty in [Type::Str, Type::Char, Type::Bytes] {
+        for &other in Type::all() {
+            assert_eq!(
+                ty.is_comparable_with(other),
+                ty == other,
+                "{} compared with {}",
+                ty,
+                other
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bool_is_not_comparable() {
+    for &other in Type::all() {
+        assert!(!Type::Bool.is_comparable_with(other));
+        assert!(!other.is_comparable_with(Type::Bool));
+    }
+}
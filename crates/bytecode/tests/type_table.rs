@@ -0,0 +1,126 @@
+use bytecode::opcode::OpCode;
+use bytecode::types::Type;
+use bytecode::types::TypeError;
+use bytecode::values::{Value, ValueError};
+
+/// A sample value of each type, for checking the encoded payload size.
+fn sample(ty: Type) -> Value {
+    match ty {
+        Type::Int => Value::Int(-1),
+        Type::Float => Value::Float(1.5),
+        Type::Bool => Value::Bool(true),
+        Type::Str => Value::Str(String::from("sample")),
+        Type::Char => Value::Char('s'),
+        Type::Float32 => Value::Float32(1.5),
+        Type::Bytes => Value::Bytes(vec![1, 2, 3]),
+        Type::Range => Value::Range {
+            start: -1,
+            end: 10,
+            inclusive: true,
+        },
+        Type::Symbol => Value::Symbol(String::from("sample")),
+        Type::Tuple => Value::Tuple(vec![Value::Int(-1), Value::Str(String::from("sample"))]),
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_every_type_roundtrips_through_u8() {
+    for &ty in Type::all() {
+        let decoded = Type::try_from(ty as u8).expect("Listed type should decode");
+        assert_eq!(decoded, ty);
+    }
+}
+
+#[test]
+fn test_all_lists_every_decodable_type() {
+    let decodable: Vec<Type> = (0..=u8::MAX)
+        .filter_map(|byte| Type::try_from(byte).ok())
+        .collect();
+    assert_eq!(
+        decodable,
+        Type::all(),
+        "Type::all() is out of sync with TryFrom<u8>"
+    );
+}
+
+#[test]
+fn test_every_type_has_a_display_name() {
+    for &ty in Type::all() {
+        assert!(!ty.to_string().is_empty(), "{:?} has an empty name", ty);
+    }
+}
+
+#[test]
+fn test_payload_size_matches_encoding() {
+    for &ty in Type::all() {
+        let value = sample(ty);
+        assert_eq!(Type::from(&value), ty);
+        let bytes: Vec<u8> = value.into();
+        if let Some(size) = ty.payload_size() {
+            assert_eq!(bytes.len(), 1 + size, "Payload size of {}", ty);
+        }
+    }
+}
+
+#[test]
+fn test_type_tags_do_not_overlap_opcodes() {
+    for &ty in Type::all() {
+        let tag = ty as u8;
+        assert_eq!(tag & 0xF0, 0x20, "{} tag {:#04x} is outside 0x2_", ty, tag);
+        assert!(
+            OpCode::try_from(tag).is_err(),
+            "{} tag {:#04x} is also an opcode",
+            ty,
+            tag
+        );
+    }
+
+    for &op in OpCode::all() {
+        assert!(
+            Type::try_from(op as u8).is_err(),
+            "{:?} opcode is also a type tag",
+            op
+        );
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_peek_reads_the_tag_of_every_type() {
+    for &ty in Type::all() {
+        let bytes: Vec<u8> = sample(ty).into();
+        assert_eq!(Type::peek(&bytes).expect("Encoded tag should peek"), ty);
+        assert_eq!(
+            Type::try_from(bytes.as_slice()).expect("Encoded tag should decode"),
+            ty
+        );
+    }
+}
+
+#[test]
+fn test_peek_empty_slice_is_no_tag() {
+    match Type::peek(&[]) {
+        Err(ValueError::NoTag) => {} // Expected
+        other => panic!("Expected NoTag error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_peek_unknown_tag_is_type_error() {
+    match Type::peek(&[0xFF, 0x00]) {
+        Err(ValueError::Type(TypeError::InvalidType(0xFF))) => {} // Expected
+        other => panic!("Expected InvalidType error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unknown_tag_error_message_names_the_byte() {
+    match Type::peek(&[0xFF]) {
+        Err(error) => assert_eq!(
+            error.to_string(),
+            "Buffer has an unreadable type tag: Invalid Type: 255"
+        ),
+        other => panic!("Expected InvalidType error, got: {:?}", other),
+    }
+}
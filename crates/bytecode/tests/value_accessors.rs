@@ -0,0 +1,64 @@
+use bytecode::values::Value;
+
+#[test]
+fn test_accessors_return_matching_variant() {
+    assert_eq!(Value::Int(3).as_int(), Some(3));
+    assert_eq!(Value::Float(1.5).as_float(), Some(1.5));
+    assert_eq!(Value::Bool(true).as_bool(), Some(true));
+    assert_eq!(Value::Str(String::from("hi")).as_str(), Some("hi"));
+    assert_eq!(Value::Char('x').as_char(), Some('x'));
+}
+
+#[test]
+fn test_accessors_do_not_convert_between_variants() {
+    assert_eq!(Value::Int(3).as_float(), None);
+    assert_eq!(Value::Float(3.0).as_int(), None);
+    assert_eq!(Value::Int(1).as_bool(), None);
+    assert_eq!(Value::Char('a').as_str(), None);
+    assert_eq!(Value::Str(String::from("a")).as_char(), None);
+}
+
+#[test]
+fn test_accessors_do_not_consume_value() {
+    let value = Value::Str(String::from("kept"));
+    assert_eq!(value.as_str(), Some("kept"));
+    assert_eq!(value, Value::Str(String::from("kept")));
+}
+
+#[test]
+fn test_as_f64_widens_numeric_values() {
+    assert_eq!(Value::Int(-7).as_f64(), Some(-7.0));
+    assert_eq!(Value::Float(2.5).as_f64(), Some(2.5));
+    assert_eq!(Value::Float32(0.5).as_f64(), Some(0.5));
+}
+
+#[test]
+fn test_as_f64_rejects_non_numeric_values() {
+    assert_eq!(Value::Str(String::from("1.0")).as_f64(), None);
+    assert_eq!(Value::Bool(true).as_f64(), None);
+    assert_eq!(Value::Char('1').as_f64(), None);
+}
+
+#[test]
+fn test_float_class_checks() {
+    let nan = Value::Float(f64::NAN);
+    assert!(nan.is_nan());
+    assert!(!nan.is_infinite());
+    assert!(!nan.is_finite());
+
+    let infinity = Value::Float32(f32::NEG_INFINITY);
+    assert!(!infinity.is_nan());
+    assert!(infinity.is_infinite());
+    assert!(!infinity.is_finite());
+
+    assert!(Value::Float(1.5).is_finite());
+}
+
+#[test]
+fn test_float_class_checks_are_false_for_other_variants() {
+    let int = Value::Int(5);
+    assert!(!int.is_nan());
+    assert!(!int.is_infinite());
+    assert!(!int.is_finite());
+    assert!(!Value::Str(String::from("NaN")).is_nan());
+}
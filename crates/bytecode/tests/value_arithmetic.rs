@@ -0,0 +1,251 @@
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_int_arithmetic() {
+    let sum = (Value::Int(2) + Value::Int(3)).expect("Int add should succeed");
+    assert_eq!(sum, Value::Int(5));
+    let difference = (Value::Int(2) - Value::Int(3)).expect("Int sub should succeed");
+    assert_eq!(difference, Value::Int(-1));
+    let product = (Value::Int(2) * Value::Int(3)).expect("Int mul should succeed");
+    assert_eq!(product, Value::Int(6));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_mixed_numeric_arithmetic_promotes() {
+    let sum = (Value::Int(1) + Value::Float(0.5)).expect("Int + Float should succeed");
+    assert_eq!(sum, Value::Float(1.5));
+    let sum = (Value::Float32(1.5) + Value::Float32(1.0)).expect("Float32 add should succeed");
+    assert_eq!(sum, Value::Float32(2.5));
+    let sum = (Value::Float32(1.5) + Value::Float(1.0)).expect("Float32 + Float should succeed");
+    assert_eq!(sum, Value::Float(2.5));
+}
+
+#[test]
+fn test_checked_add_overflow_returns_error() {
+    let result = Value::Int(isize::MAX) + Value::Int(1);
+    match result {
+        Err(ValueError::IntOverflow { op: "add" }) => {} // Expected
+        other => panic!("Expected IntOverflow error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_checked_sub_and_mul_overflow_return_error() {
+    let result = Value::Int(isize::MIN) - Value::Int(1);
+    assert!(
+        matches!(result, Err(ValueError::IntOverflow { op: "subtract" })),
+        "Expected IntOverflow error, got: {:?}",
+        result
+    );
+    let result = Value::Int(isize::MAX) * Value::Int(2);
+    assert!(
+        matches!(result, Err(ValueError::IntOverflow { op: "multiply" })),
+        "Expected IntOverflow error, got: {:?}",
+        result
+    );
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_wrapping_arithmetic_wraps() {
+    let sum = Value::Int(isize::MAX)
+        .wrapping_add(Value::Int(1))
+        .expect("wrapping_add should succeed");
+    assert_eq!(sum, Value::Int(isize::MIN));
+    let difference = Value::Int(isize::MIN)
+        .wrapping_sub(Value::Int(1))
+        .expect("wrapping_sub should succeed");
+    assert_eq!(difference, Value::Int(isize::MAX));
+    let product = Value::Int(isize::MAX)
+        .wrapping_mul(Value::Int(2))
+        .expect("wrapping_mul should succeed");
+    assert_eq!(product, Value::Int(-2));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_wrapping_arithmetic_matches_char_operators() {
+    let next = Value::Char('a')
+        .wrapping_add(Value::Int(1))
+        .expect("wrapping_add should shift the char");
+    assert_eq!(next, Value::Char('b'));
+    let next = Value::Int(2)
+        .wrapping_add(Value::Char('a'))
+        .expect("wrapping_add should shift the char");
+    assert_eq!(next, Value::Char('c'));
+    let previous = Value::Char('b')
+        .wrapping_sub(Value::Int(1))
+        .expect("wrapping_sub should shift the char");
+    assert_eq!(previous, Value::Char('a'));
+    let distance = Value::Char('z')
+        .wrapping_sub(Value::Char('a'))
+        .expect("wrapping_sub should give the distance");
+    assert_eq!(distance, Value::Int(25));
+
+    let result = Value::Char('\0').wrapping_sub(Value::Int(1));
+    assert!(
+        matches!(result, Err(ValueError::InvalidChar { code_point: -1 })),
+        "Expected InvalidChar error, got: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_non_numeric_arithmetic_returns_error() {
+    let result = Value::Str("a".into()) + Value::Int(1);
+    match result {
+        Err(ValueError::InvalidOperation {
+            op: "add",
+            lhs: Type::Str,
+            rhs: Type::Int,
+        }) => {} // Expected
+        other => panic!("Expected InvalidOperation error, got: {:?}", other),
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_char_plus_int_shifts_character() {
+    let next = (Value::Char('a') + Value::Int(1)).expect("Char + Int should succeed");
+    assert_eq!(next, Value::Char('b'));
+    let next = (Value::Int(2) + Value::Char('a')).expect("Int + Char should succeed");
+    assert_eq!(next, Value::Char('c'));
+    let previous = (Value::Char('b') - Value::Int(1)).expect("Char - Int should succeed");
+    assert_eq!(previous, Value::Char('a'));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_char_minus_char_is_distance() {
+    let distance = (Value::Char('z') - Value::Char('a')).expect("Char - Char should succeed");
+    assert_eq!(distance, Value::Int(25));
+    let distance = (Value::Char('a') - Value::Char('z')).expect("Char - Char should succeed");
+    assert_eq!(distance, Value::Int(-25));
+}
+
+#[test]
+fn test_char_arithmetic_into_surrogates_returns_error() {
+    let result = Value::Char('\u{D7FF}') + Value::Int(1);
+    match result {
+        Err(ValueError::InvalidChar { code_point: 0xD800 }) => {} // Expected
+        other => panic!("Expected InvalidChar error, got: {:?}", other),
+    }
+
+    let result = Value::Char('\0') - Value::Int(1);
+    assert!(
+        matches!(result, Err(ValueError::InvalidChar { code_point: -1 })),
+        "Expected InvalidChar error, got: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_other_char_arithmetic_returns_error() {
+    let result = Value::Int(1) - Value::Char('a');
+    assert!(
+        matches!(result, Err(ValueError::InvalidOperation { .. })),
+        "Expected InvalidOperation error, got: {:?}",
+        result
+    );
+    let result = Value::Char('a') + Value::Char('b');
+    assert!(
+        matches!(result, Err(ValueError::InvalidOperation { .. })),
+        "Expected InvalidOperation error, got: {:?}",
+        result
+    );
+    let result = Value::Char('a') * Value::Int(2);
+    assert!(
+        matches!(result, Err(ValueError::InvalidOperation { .. })),
+        "Expected InvalidOperation error, got: {:?}",
+        result
+    );
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_int_power() {
+    let result = Value::Int(2)
+        .pow(Value::Int(10))
+        .expect("2^10 should succeed");
+    assert_eq!(result, Value::Int(1024));
+    let result = Value::Int(-3)
+        .pow(Value::Int(0))
+        .expect("(-3)^0 should succeed");
+    assert_eq!(result, Value::Int(1));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_negative_exponent_promotes_to_float() {
+    let result = Value::Int(2)
+        .pow(Value::Int(-1))
+        .expect("2^-1 should succeed");
+    assert_eq!(result, Value::Float(0.5));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_float_power() {
+    let result = Value::Float(4.0)
+        .pow(Value::Float(0.5))
+        .expect("4.0^0.5 should succeed");
+    assert_eq!(result, Value::Float(2.0));
+    let result = Value::Int(9)
+        .pow(Value::Float(0.5))
+        .expect("9^0.5 should succeed");
+    assert_eq!(result, Value::Float(3.0));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_unit_bases_accept_exponents_beyond_u32() {
+    // `isize::MAX - 1` is even and `isize::MAX` odd; both exceed `u32::MAX`
+    // on 64-bit targets.
+    let even = isize::MAX - 1;
+    let odd = isize::MAX;
+    let cases = [
+        (1, even, 1),
+        (1, odd, 1),
+        (0, even, 0),
+        (0, odd, 0),
+        (-1, even, 1),
+        (-1, odd, -1),
+    ];
+    for (base, exponent, expected) in cases {
+        let result = Value::Int(base)
+            .pow(Value::Int(exponent))
+            .expect("Unit bases should never overflow");
+        assert_eq!(result, Value::Int(expected), "{base}^{exponent}");
+    }
+
+    let result = Value::Int(0)
+        .pow(Value::Int(0))
+        .expect("0^0 should succeed");
+    assert_eq!(result, Value::Int(1));
+}
+
+#[test]
+fn test_int_power_overflow_returns_error() {
+    for exponent in [isize::BITS as isize, isize::MAX] {
+        match Value::Int(2).pow(Value::Int(exponent)) {
+            Err(ValueError::IntOverflow { op: "power" }) => {} // Expected
+            other => panic!("Expected IntOverflow error, got: {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_non_numeric_power_returns_error() {
+    let result = Value::Str(String::from("2")).pow(Value::Int(2));
+    assert!(
+        matches!(
+            result,
+            Err(ValueError::InvalidOperation { op: "power", .. })
+        ),
+        "Expected InvalidOperation error, got: {:?}",
+        result
+    );
+}
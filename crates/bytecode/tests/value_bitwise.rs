@@ -0,0 +1,64 @@
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_bitwise_operations_on_ints() {
+    let and = (Value::Int(0b1100) & Value::Int(0b1010)).expect("BitAnd should succeed");
+    assert_eq!(and, Value::Int(0b1000));
+    let or = (Value::Int(0b1100) | Value::Int(0b1010)).expect("BitOr should succeed");
+    assert_eq!(or, Value::Int(0b1110));
+    let xor = (Value::Int(0b1100) ^ Value::Int(0b1010)).expect("BitXor should succeed");
+    assert_eq!(xor, Value::Int(0b0110));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_shifts_on_ints() {
+    let left = (Value::Int(1) << Value::Int(4)).expect("ShiftLeft should succeed");
+    assert_eq!(left, Value::Int(16));
+    let right = (Value::Int(-16) >> Value::Int(2)).expect("ShiftRight should succeed");
+    assert_eq!(right, Value::Int(-4), "ShiftRight should preserve the sign");
+    let widest = (Value::Int(1) << Value::Int(isize::BITS as isize - 1))
+        .expect("ShiftLeft by BITS - 1 should succeed");
+    assert_eq!(widest, Value::Int(isize::MIN));
+}
+
+#[test]
+fn test_shift_out_of_range_returns_error() {
+    let too_far = isize::BITS as isize;
+    for amount in [too_far, -1] {
+        let result = Value::Int(1) << Value::Int(amount);
+        match result {
+            Err(ValueError::InvalidShift { amount: reported }) => assert_eq!(reported, amount),
+            other => panic!("Expected InvalidShift error, got: {:?}", other),
+        }
+    }
+
+    let result = Value::Int(1) >> Value::Int(too_far);
+    assert!(
+        matches!(result, Err(ValueError::InvalidShift { .. })),
+        "Expected InvalidShift error, got: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_bitwise_on_non_int_returns_error() {
+    let result = Value::Float(1.0) & Value::Int(1);
+    match result {
+        Err(ValueError::InvalidOperation {
+            lhs: Type::Float,
+            rhs: Type::Int,
+            ..
+        }) => {} // Expected
+        other => panic!("Expected InvalidOperation error, got: {:?}", other),
+    }
+
+    let result = Value::Int(1) << Value::Bool(true);
+    assert!(
+        matches!(result, Err(ValueError::InvalidOperation { .. })),
+        "Expected InvalidOperation error, got: {:?}",
+        result
+    );
+}
@@ -0,0 +1,40 @@
+use bytecode::byte_order::ByteOrder;
+use bytecode::types::Type;
+use bytecode::values::Value;
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_int_roundtrips_in_both_orders() {
+    for order in [ByteOrder::Little, ByteOrder::Big] {
+        let original = Value::Int(0x0102_0304);
+        let bytes = original.encode_with(order);
+        let decoded = Value::decode_with(&bytes, order).expect("Decode should succeed");
+        assert_eq!(decoded, original, "Int should roundtrip in {:?}", order);
+    }
+}
+
+#[test]
+fn test_big_endian_int_layout() {
+    let bytes = Value::Int(0x0102_0304).encode_with(ByteOrder::Big);
+    assert_eq!(bytes, vec![Type::Int as u8, 0, 0, 0, 0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_str_length_prefix_follows_order() {
+    let original = Value::Str(String::from("abc"));
+    let bytes = original.encode_with(ByteOrder::Big);
+    assert_eq!(&bytes[1..5], &[0, 0, 0, 3]);
+    let decoded = Value::decode_with(&bytes, ByteOrder::Big).expect("Decode should succeed");
+    assert_eq!(decoded, original);
+}
+
+#[test]
+fn test_default_order_is_little_endian() {
+    let original = Value::Float(1.5);
+    assert_eq!(ByteOrder::default(), ByteOrder::Little);
+    assert_eq!(
+        original.encode_with(ByteOrder::default()),
+        Vec::<u8>::from(original)
+    );
+}
@@ -0,0 +1,73 @@
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+
+fn samples() -> Vec<Value> {
+    vec![
+        Value::Int(65),
+        Value::Float(1.5),
+        Value::Bool(true),
+        Value::Str(String::from("x")),
+        Value::Char('A'),
+        Value::Float32(0.5),
+        Value::Bytes(vec![b'h', 0]),
+        Value::Symbol(String::from("y")),
+    ]
+}
+
+/// The expected result of coercing `value` to `target`, or `None` when the
+/// coercion is disallowed.
+fn expected(value: &Value, target: Type) -> Option<Value> {
+    match (value, target) {
+        (value, target) if Type::from(value) == target => Some(value.clone()),
+        (Value::Int(65), Type::Float) => Some(Value::Float(65.0)),
+        (Value::Int(65), Type::Char) => Some(Value::Char('A')),
+        (Value::Int(65), Type::Str) => Some(Value::Str(String::from("65"))),
+        (Value::Float(_), Type::Str) => Some(Value::Str(String::from("1.5"))),
+        (Value::Bool(true), Type::Int) => Some(Value::Int(1)),
+        (Value::Bool(true), Type::Str) => Some(Value::Str(String::from("true"))),
+        (Value::Char('A'), Type::Int) => Some(Value::Int(65)),
+        (Value::Char('A'), Type::Str) => Some(Value::Str(String::from("A"))),
+        (Value::Float32(_), Type::Float) => Some(Value::Float(0.5)),
+        (Value::Float32(_), Type::Str) => Some(Value::Str(String::from("0.5"))),
+        (Value::Bytes(_), Type::Str) => Some(Value::Str(String::from("b\"h\\x00\""))),
+        (Value::Str(_), Type::Symbol) => Some(Value::Symbol(String::from("x"))),
+        (Value::Symbol(_), Type::Str) => Some(Value::Str(String::from("y"))),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_coercion_matrix() {
+    for value in samples() {
+        for &target in Type::all() {
+            let result = value.coerce_to(target);
+            match (expected(&value, target), result) {
+                (Some(expected), Ok(actual)) => assert_eq!(actual, expected),
+                (None, Err(ValueError::InvalidConversion { from, to })) => {
+                    assert_eq!(from, Type::from(&value));
+                    assert_eq!(to, target);
+                }
+                (expected, actual) => panic!(
+                    "Coercing {:?} to {}: expected {:?}, got {:?}",
+                    value, target, expected, actual
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_int_to_char_is_checked() {
+    let result = Value::Int(0xD800).coerce_to(Type::Char);
+    match result {
+        Err(ValueError::InvalidChar { code_point: 0xD800 }) => {} // Expected
+        other => panic!("Expected InvalidChar error, got: {:?}", other),
+    }
+
+    let result = Value::Int(-1).coerce_to(Type::Char);
+    assert!(
+        matches!(result, Err(ValueError::InvalidChar { .. })),
+        "Expected InvalidChar error, got: {:?}",
+        result
+    );
+}
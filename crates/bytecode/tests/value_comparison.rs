@@ -0,0 +1,125 @@
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+
+#[allow(clippy::expect_used)]
+fn less_equal(a: Value, b: Value) -> Value {
+    a.less_equal(&b).expect("less_equal should succeed")
+}
+
+#[allow(clippy::expect_used)]
+fn greater_equal(a: Value, b: Value) -> Value {
+    a.greater_equal(&b).expect("greater_equal should succeed")
+}
+
+#[test]
+fn test_less_equal_and_greater_equal_on_ints() {
+    assert_eq!(less_equal(Value::Int(1), Value::Int(2)), true);
+    assert_eq!(less_equal(Value::Int(2), Value::Int(2)), true);
+    assert_eq!(less_equal(Value::Int(3), Value::Int(2)), false);
+    assert_eq!(greater_equal(Value::Int(3), Value::Int(2)), true);
+    assert_eq!(greater_equal(Value::Int(2), Value::Int(2)), true);
+    assert_eq!(greater_equal(Value::Int(1), Value::Int(2)), false);
+}
+
+#[test]
+fn test_mixed_numeric_comparison_uses_values() {
+    assert_eq!(less_equal(Value::Int(1), Value::Float(1.0)), true);
+    assert_eq!(greater_equal(Value::Float(0.5), Value::Int(1)), false);
+    assert_eq!(less_equal(Value::Float32(2.5), Value::Float(2.5)), true);
+}
+
+#[test]
+fn test_nan_comparisons_are_false() {
+    let nan = Value::Float(f64::NAN);
+    assert_eq!(less_equal(nan.clone(), Value::Float(1.0)), false);
+    assert_eq!(greater_equal(nan.clone(), Value::Float(1.0)), false);
+    assert_eq!(less_equal(Value::Int(1), nan.clone()), false);
+    assert_eq!(greater_equal(nan.clone(), nan), false);
+}
+
+#[test]
+fn test_strings_compare_lexicographically() {
+    let a = Value::Str(String::from("apple"));
+    let b = Value::Str(String::from("banana"));
+    assert_eq!(less_equal(a.clone(), b.clone()), true);
+    assert_eq!(greater_equal(a, b), false);
+}
+
+#[test]
+fn test_incomparable_types_return_error() {
+    let result = Value::Str(String::from("1")).less_equal(&Value::Int(1));
+    match result {
+        Err(ValueError::InvalidOperation {
+            lhs: Type::Str,
+            rhs: Type::Int,
+            ..
+        }) => {} // Expected
+        other => panic!("Expected InvalidOperation error, got: {:?}", other),
+    }
+
+    let result = Value::Bool(true).greater_equal(&Value::Bool(false));
+    assert!(
+        matches!(result, Err(ValueError::InvalidOperation { .. })),
+        "Expected InvalidOperation error, got: {:?}",
+        result
+    );
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_approx_eq_tolerates_rounding() {
+    let sum = (Value::Float(0.1) + Value::Float(0.2)).expect("Float addition should succeed");
+    assert_ne!(sum, Value::Float(0.3));
+
+    let epsilon = Value::Float(1e-9);
+    let result = sum
+        .approx_eq(&Value::Float(0.3), &epsilon)
+        .expect("Floats are approx-comparable");
+    assert_eq!(result, Value::Bool(true));
+
+    let result = Value::Float(1.0)
+        .approx_eq(&Value::Float32(1.5), &epsilon)
+        .expect("Float and Float32 are approx-comparable");
+    assert_eq!(result, Value::Bool(false));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_approx_eq_nan_is_never_equal() {
+    let nan = Value::Float(f64::NAN);
+    let result = nan
+        .approx_eq(&nan, &Value::Float(f64::INFINITY))
+        .expect("NaN is still a float");
+    assert_eq!(result, Value::Bool(false));
+}
+
+#[test]
+fn test_approx_eq_rejects_non_floats() {
+    match Value::Int(1).approx_eq(&Value::Float(1.0), &Value::Float(0.1)) {
+        Err(ValueError::InvalidOperation { op, lhs, rhs }) => {
+            assert_eq!(op, "approx equal");
+            assert_eq!(lhs, Type::Int);
+            assert_eq!(rhs, Type::Float);
+        }
+        other => panic!("Expected InvalidOperation error, got: {:?}", other),
+    }
+
+    match Value::Float(1.0).approx_eq(&Value::Float(1.0), &Value::Int(0)) {
+        Err(ValueError::InvalidOperand { op, operand }) => {
+            assert_eq!(op, "approx equal");
+            assert_eq!(operand, Type::Int);
+        }
+        other => panic!("Expected InvalidOperand error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_mixed_comparison_does_not_round_int() {
+    let int = Value::Int((1 << 53) + 1);
+    let float = Value::Float((1u64 << 53) as f64);
+    assert_eq!(less_equal(int.clone(), float.clone()), false);
+    assert_eq!(greater_equal(int.clone(), float.clone()), true);
+    assert_eq!(greater_equal(float, int), false);
+    let max = Value::Float(isize::MAX as f64);
+    assert_eq!(less_equal(Value::Int(isize::MAX), max), true);
+}
@@ -0,0 +1,82 @@
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+
+#[test]
+fn test_into_any_boxes_the_primitive() {
+    let any = Value::Int(7).into_any();
+    assert_eq!(any.downcast_ref::<isize>(), Some(&7));
+
+    let any = Value::Str(String::from("host")).into_any();
+    assert_eq!(
+        any.downcast_ref::<String>().map(String::as_str),
+        Some("host")
+    );
+}
+
+#[test]
+fn test_any_roundtrip() {
+    let values = [
+        Value::Int(-3),
+        Value::Float(0.5),
+        Value::Bool(true),
+        Value::Char('q'),
+        Value::Float32(1.5),
+        Value::Bytes(vec![0, 255]),
+        Value::Symbol(String::from("name")),
+        Value::Tuple(vec![Value::Int(1), Value::Bool(false)]),
+    ];
+    for value in values {
+        let any = value.clone().into_any();
+        assert_eq!(Value::from_any(any.as_ref()), Some(value));
+    }
+}
+
+#[test]
+fn test_from_any_rejects_foreign_types() {
+    assert_eq!(Value::from_any(&5u64), None);
+    assert_eq!(Value::from_any(&"borrowed"), None);
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_narrow_int_conversions_in_range() {
+    assert_eq!(i32::try_from(Value::Int(-5)).expect("Fits i32"), -5);
+    assert_eq!(u32::try_from(Value::Int(5)).expect("Fits u32"), 5);
+    assert_eq!(u8::try_from(Value::Int(255)).expect("Fits u8"), 255);
+    assert_eq!(usize::try_from(Value::Int(9)).expect("Fits usize"), 9);
+}
+
+fn assert_out_of_range<T: TryFrom<Value, Error = ValueError> + std::fmt::Debug>(
+    value: isize,
+    target: &str,
+) {
+    match T::try_from(Value::Int(value)) {
+        Err(ValueError::IntOutOfRange {
+            value: actual,
+            target: actual_target,
+        }) => {
+            assert_eq!(actual, value);
+            assert_eq!(actual_target, target);
+        }
+        other => panic!("Expected IntOutOfRange error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_narrow_int_conversions_out_of_range() {
+    assert_out_of_range::<i32>(i32::MAX as isize + 1, "i32");
+    assert_out_of_range::<u32>(-1, "u32");
+    assert_out_of_range::<u8>(256, "u8");
+    assert_out_of_range::<usize>(-1, "usize");
+}
+
+#[test]
+fn test_narrow_int_conversions_require_int() {
+    match u8::try_from(Value::Float(1.0)) {
+        Err(ValueError::InvalidConversion { from, to }) => {
+            assert_eq!(from, Type::Float);
+            assert_eq!(to, Type::Int);
+        }
+        other => panic!("Expected InvalidConversion error, got: {:?}", other),
+    }
+}
@@ -0,0 +1,48 @@
+use bytecode::values::{Value, ValueError};
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_int_with_zero_padding_decodes() {
+    let mut bytes: Vec<u8> = Value::Int(42).into();
+    bytes.extend([0, 0]);
+    let decoded = Value::decode_aligned(&bytes, 4).expect("Padded Int should decode");
+    assert_eq!(decoded, Value::Int(42));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_unpadded_value_decodes() {
+    let bytes: Vec<u8> = Value::Bool(true).into();
+    let decoded = Value::decode_aligned(&bytes, 8).expect("Unpadded value should decode");
+    assert_eq!(decoded, Value::Bool(true));
+}
+
+#[test]
+fn test_padding_must_be_shorter_than_alignment() {
+    let mut bytes: Vec<u8> = Value::Int(42).into();
+    bytes.extend([0; 4]);
+    match Value::decode_aligned(&bytes, 4) {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_padding_must_be_zero() {
+    let mut bytes: Vec<u8> = Value::Int(42).into();
+    bytes.extend([0, 1]);
+    match Value::decode_aligned(&bytes, 4) {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_default_decoding_stays_strict() {
+    let mut bytes: Vec<u8> = Value::Int(42).into();
+    bytes.extend([0, 0]);
+    match Value::try_from(bytes) {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
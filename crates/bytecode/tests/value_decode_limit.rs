@@ -0,0 +1,47 @@
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+
+#[test]
+fn test_forged_str_length_is_rejected() {
+    let mut forged = vec![Type::Str as u8];
+    forged.extend_from_slice(&u32::MAX.to_le_bytes());
+    forged.extend_from_slice(b"hi");
+
+    match Value::decode_with_limit(&forged, 1024) {
+        Err(ValueError::LengthLimitExceeded { claimed, max }) => {
+            assert_eq!(claimed, u64::from(u32::MAX));
+            assert_eq!(max, 1024);
+        }
+        other => panic!("Expected LengthLimitExceeded error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_forged_bytes_length_is_rejected() {
+    // LEB128 for u64::MAX: nine 0xFF continuation bytes and a final 0x01.
+    let mut forged = vec![Type::Bytes as u8];
+    forged.extend_from_slice(&[0xFF; 9]);
+    forged.push(0x01);
+
+    match Value::decode_with_limit(&forged, 1024) {
+        Err(ValueError::LengthLimitExceeded { claimed, max }) => {
+            assert_eq!(claimed, u64::MAX);
+            assert_eq!(max, 1024);
+        }
+        other => panic!("Expected LengthLimitExceeded error, got: {:?}", other),
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_lengths_within_limit_decode() {
+    let value = Value::Str(String::from("four"));
+    let bytes: Vec<u8> = value.clone().into();
+    let decoded = Value::decode_with_limit(&bytes, 4).expect("Length at the limit should decode");
+    assert_eq!(decoded, value);
+
+    match Value::decode_with_limit(&bytes, 3) {
+        Err(ValueError::LengthLimitExceeded { claimed: 4, max: 3 }) => {}
+        other => panic!("Expected LengthLimitExceeded error, got: {:?}", other),
+    }
+}
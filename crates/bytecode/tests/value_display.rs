@@ -0,0 +1,30 @@
+use bytecode::values::Value;
+
+#[test]
+fn test_float_default_formatting() {
+    assert_eq!(Value::Float(1.23456).to_string(), "1.23456");
+    assert_eq!(Value::Float(2.0).to_string(), "2");
+    assert_eq!(Value::Float32(0.5).to_string(), "0.5");
+}
+
+#[test]
+fn test_float_precision_is_honored() {
+    assert_eq!(format!("{:.2}", Value::Float(1.23456)), "1.23");
+    assert_eq!(format!("{:.3}", Value::Float(2.0)), "2.000");
+    assert_eq!(format!("{:.1}", Value::Float32(0.25)), "0.2");
+}
+
+#[test]
+fn test_special_floats_ignore_precision() {
+    assert_eq!(format!("{:.2}", Value::Float(f64::NAN)), "NaN");
+    assert_eq!(format!("{:.2}", Value::Float(f64::INFINITY)), "inf");
+    assert_eq!(format!("{:.2}", Value::Float(f64::NEG_INFINITY)), "-inf");
+}
+
+#[test]
+fn test_precision_leaves_other_values_alone() {
+    assert_eq!(format!("{:.2}", Value::Int(7)), "7");
+    assert_eq!(format!("{:.2}", Value::Str(String::from("long"))), "long");
+    let tuple = Value::Tuple(vec![Value::Float(1.23456), Value::Str(String::from("abc"))]);
+    assert_eq!(format!("{:.1}", tuple), "(1.2, \"abc\")");
+}
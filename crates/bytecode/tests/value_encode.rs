@@ -0,0 +1,141 @@
+use bytecode::values::{Value, ValueError};
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_try_encode_matches_infallible_encoding() {
+    let values = vec![
+        Value::Int(42),
+        Value::Float(1.5),
+        Value::Bool(true),
+        Value::Str(String::from("Hello, World!")),
+        Value::Char('a'),
+        Value::Float32(0.25),
+    ];
+
+    for value in values {
+        let encoded = value.try_encode().expect("try_encode should succeed");
+        assert_eq!(encoded, Vec::<u8>::from(value));
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_chars_roundtrip_at_full_width() {
+    for ch in ['\0', 'a', '\u{FF}', '\u{100}', '🥡', char::MAX] {
+        let encoded = Value::Char(ch)
+            .try_encode()
+            .expect("Every char should encode");
+        assert_eq!(encoded.len(), 5);
+        let decoded = Value::try_from(encoded).expect("Encoded char should decode");
+        assert_eq!(decoded, Value::Char(ch));
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_str_char_at_result_encodes() {
+    let text = Value::Str(String::from("héllo🥡"));
+    let ch = text
+        .str_char_at(&Value::Int(5))
+        .expect("Index 5 should be in bounds");
+    let encoded = Vec::<u8>::from(ch.clone());
+    assert_eq!(Value::try_from(encoded).expect("Char should decode"), ch);
+}
+
+#[test]
+fn test_decode_rejects_invalid_char_scalar() {
+    let mut bytes = vec![0x24];
+    bytes.extend_from_slice(&0xD800u32.to_le_bytes());
+    match Value::try_from(bytes) {
+        Err(ValueError::InvalidChar { code_point: 0xD800 }) => {} // Expected
+        other => panic!("Expected InvalidChar error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_max_str_len_matches_length_prefix() {
+    assert_eq!(Value::MAX_STR_LEN, u32::MAX as usize);
+}
+
+#[test]
+fn test_canonicalize_collapses_nan_payloads() {
+    let mut quiet = Value::Float(f64::NAN);
+    let mut payload = Value::Float(f64::from_bits(0x7ff8_0000_dead_beef));
+    let mut negative = Value::Float(-f64::NAN);
+    quiet.canonicalize();
+    payload.canonicalize();
+    negative.canonicalize();
+
+    let expected = Vec::<u8>::from(quiet);
+    assert_eq!(Vec::<u8>::from(payload), expected);
+    assert_eq!(Vec::<u8>::from(negative), expected);
+}
+
+#[test]
+fn test_canonicalize_turns_negative_zero_positive() {
+    let mut value = Value::Float(-0.0);
+    value.canonicalize();
+    assert_eq!(Vec::<u8>::from(value), Vec::<u8>::from(Value::Float(0.0)));
+
+    let mut value = Value::Float32(-0.0);
+    value.canonicalize();
+    assert_eq!(Vec::<u8>::from(value), Vec::<u8>::from(Value::Float32(0.0)));
+}
+
+#[test]
+fn test_canonicalize_leaves_other_values_alone() {
+    let values = vec![
+        Value::Float(-1.5),
+        Value::Float32(f32::NEG_INFINITY),
+        Value::Int(0),
+        Value::Str(String::from("NaN")),
+    ];
+
+    for value in values {
+        let mut canonical = value.clone();
+        canonical.canonicalize();
+        assert_eq!(canonical, value);
+    }
+}
+
+#[test]
+fn test_encoding_preserves_nan_bits_by_default() {
+    let nan = f64::from_bits(0x7ff8_0000_dead_beef);
+    let bytes = Vec::<u8>::from(Value::Float(nan));
+    assert_eq!(&bytes[1..], &nan.to_le_bytes());
+}
+
+#[test]
+fn test_write_to_appends_to_existing_buffer() {
+    let mut buffer = vec![0xAA, 0xBB];
+    Value::Int(42).write_to(&mut buffer);
+    Value::Str(String::from("hi")).write_to(&mut buffer);
+
+    let mut expected = vec![0xAA, 0xBB];
+    expected.extend(Vec::<u8>::from(Value::Int(42)));
+    expected.extend(Vec::<u8>::from(Value::Str(String::from("hi"))));
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_write_to_output_decodes_back() {
+    let values = vec![
+        Value::Bool(true),
+        Value::Bytes(vec![1, 2]),
+        Value::Char('z'),
+    ];
+    let mut buffer = Vec::new();
+    for value in &values {
+        value.write_to(&mut buffer);
+    }
+
+    let mut offset = 0;
+    for value in values {
+        let (decoded, consumed) =
+            Value::decode_prefix(&buffer[offset..]).expect("Prefix decode should succeed");
+        assert_eq!(decoded, value);
+        offset += consumed;
+    }
+    assert_eq!(offset, buffer.len());
+}
@@ -0,0 +1,109 @@
+use bytecode::values::Value;
+
+#[test]
+fn test_value_eq_treats_int_and_float_numerically() {
+    assert!(Value::Int(1).value_eq(&Value::Float(1.0)));
+    assert!(Value::Float(1.0).value_eq(&Value::Int(1)));
+    assert!(Value::Int(-3).value_eq(&Value::Float32(-3.0)));
+    assert!(Value::Float32(0.5).value_eq(&Value::Float(0.5)));
+    assert!(!Value::Int(1).value_eq(&Value::Float(1.5)));
+}
+
+#[test]
+fn test_value_eq_is_structural_for_other_types() {
+    assert!(Value::Str("a".into()).value_eq(&Value::Str("a".into())));
+    assert!(!Value::Str("1".into()).value_eq(&Value::Int(1)));
+    assert!(!Value::Bool(true).value_eq(&Value::Int(1)));
+    assert!(!Value::Char('a').value_eq(&Value::Str("a".into())));
+}
+
+#[test]
+fn test_value_eq_nan_is_never_equal() {
+    assert!(!Value::Float(f64::NAN).value_eq(&Value::Float(f64::NAN)));
+}
+
+#[test]
+fn test_partial_eq_stays_exact() {
+    assert_ne!(Value::Int(1), Value::Float(1.0));
+    assert_eq!(Value::Int(1), Value::Int(1));
+}
+
+#[test]
+fn test_partial_eq_with_primitives() {
+    assert_eq!(Value::Int(3), 3);
+    assert_eq!(3, Value::Int(3));
+    assert_eq!(Value::Float(1.5), 1.5);
+    assert_eq!(1.5, Value::Float(1.5));
+    assert_eq!(Value::Bool(true), true);
+    assert_eq!(false, Value::Bool(false));
+    assert_eq!(Value::Char('c'), 'c');
+    assert_eq!('c', Value::Char('c'));
+    assert_eq!(Value::Str(String::from("hi")), "hi");
+    assert_eq!("hi", Value::Str(String::from("hi")));
+}
+
+#[test]
+fn test_partial_eq_with_primitives_requires_matching_variant() {
+    assert_ne!(Value::Int(1), 1.0);
+    assert_ne!(Value::Float(1.0), 1);
+    assert_ne!(Value::Int(1), true);
+    assert_ne!(Value::Char('a'), "a");
+    assert_ne!(Value::Str(String::from("a")), 'a');
+    assert_ne!(Value::Int(3), 4);
+}
+
+#[test]
+fn test_symbol_never_equals_str() {
+    let symbol = Value::Symbol(String::from("x"));
+    let string = Value::Str(String::from("x"));
+    assert_ne!(symbol, string);
+    assert!(!symbol.value_eq(&string));
+    assert_ne!(symbol, "x");
+    assert_eq!(symbol, Value::Symbol(String::from("x")));
+    assert_ne!(symbol, Value::Symbol(String::from("y")));
+}
+
+#[test]
+fn test_fast_eq_matches_partial_eq() {
+    let values = [
+        Value::Int(1),
+        Value::Float(1.0),
+        Value::Float(f64::NAN),
+        Value::Str(String::from("ab")),
+        Value::Str(String::from("abc")),
+        Value::Symbol(String::from("ab")),
+        Value::Bytes(vec![b'a', b'b']),
+        Value::Tuple(vec![Value::Int(1), Value::Str(String::from("ab"))]),
+        Value::Tuple(vec![Value::Int(1), Value::Str(String::from("ac"))]),
+        Value::Tuple(vec![Value::Int(1)]),
+        Value::Tuple(vec![Value::Float(f64::NAN)]),
+    ];
+    for a in &values {
+        for b in &values {
+            assert_eq!(a.fast_eq(b), a == b, "{:?} vs {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_value_eq_compares_int_and_float_exactly() {
+    let float = (1u64 << 53) as f64;
+    assert!(!Value::Int((1 << 53) + 1).value_eq(&Value::Float(float)));
+    assert!(!Value::Float(float).value_eq(&Value::Int((1 << 53) + 1)));
+    assert!(Value::Int(1 << 53).value_eq(&Value::Float(float)));
+    assert!(!Value::Int(isize::MAX).value_eq(&Value::Float(isize::MAX as f64)));
+    assert!(!Value::Int(0).value_eq(&Value::Float(f64::NAN)));
+    assert!(!Value::Int(isize::MAX).value_eq(&Value::Float(f64::INFINITY)));
+}
+
+#[test]
+fn test_value_eq_recurses_into_tuples() {
+    let ints = Value::Tuple(vec![Value::Int(1), Value::Str("a".into())]);
+    let floats = Value::Tuple(vec![Value::Float(1.0), Value::Str("a".into())]);
+    assert!(ints.value_eq(&floats));
+    assert!(!ints.value_eq(&Value::Tuple(vec![Value::Float(1.0)])));
+    assert!(!ints.value_eq(&Value::Tuple(vec![
+        Value::Float(1.5),
+        Value::Str("a".into())
+    ])));
+}
@@ -0,0 +1,31 @@
+use bytecode::values::Value;
+
+#[test]
+fn test_scalars_own_no_heap() {
+    let scalars = vec![
+        Value::Int(42),
+        Value::Float(1.5),
+        Value::Bool(true),
+        Value::Char('a'),
+        Value::Float32(0.5),
+    ];
+
+    for value in scalars {
+        assert_eq!(value.heap_size(), 0, "{:?} should own no heap", value);
+    }
+}
+
+#[test]
+fn test_str_heap_size_is_capacity() {
+    let mut s = String::with_capacity(64);
+    s.push_str("hi");
+    assert_eq!(Value::Str(s).heap_size(), 64);
+    assert_eq!(Value::Str(String::new()).heap_size(), 0);
+}
+
+#[test]
+fn test_bytes_heap_size_is_capacity() {
+    let mut b = Vec::with_capacity(32);
+    b.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(Value::Bytes(b).heap_size(), 32);
+}
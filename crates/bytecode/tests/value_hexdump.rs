@@ -0,0 +1,48 @@
+use bytecode::values::Value;
+
+#[test]
+fn test_int_hexdump_snapshot() {
+    assert_eq!(
+        Value::Int(42).hexdump(),
+        "20 | Int tag\n\
+         2a 00 00 00 00 00 00 00 | 42"
+    );
+}
+
+#[test]
+fn test_str_hexdump_snapshot() {
+    assert_eq!(
+        Value::Str(String::from("hi")).hexdump(),
+        "23 | String tag\n\
+         02 00 00 00 | length 2\n\
+         68 69 | \"hi\""
+    );
+}
+
+#[test]
+fn test_empty_str_hexdump_omits_data_line() {
+    assert_eq!(
+        Value::Str(String::new()).hexdump(),
+        "23 | String tag\n\
+         00 00 00 00 | length 0"
+    );
+}
+
+#[test]
+fn test_bytes_hexdump_snapshot() {
+    assert_eq!(
+        Value::Bytes(vec![0xde, 0xad]).hexdump(),
+        "29 | Bytes tag\n\
+         02 | length 2\n\
+         de ad | 2 raw bytes"
+    );
+}
+
+#[test]
+fn test_char_hexdump_shows_full_code_point() {
+    assert_eq!(
+        Value::Char('🥡').hexdump(),
+        "24 | Char tag\n\
+         61 f9 01 00 | '🥡' (U+1F961)"
+    );
+}
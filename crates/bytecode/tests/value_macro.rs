@@ -0,0 +1,37 @@
+use bytecode::value;
+use bytecode::values::Value;
+
+#[test]
+fn test_value_macro_integer_literal() {
+    assert_eq!(value!(42), Value::Int(42));
+    assert_eq!(value!(-7), Value::Int(-7));
+}
+
+#[test]
+fn test_value_macro_float_literal() {
+    assert_eq!(value!(1.5), Value::Float(1.5));
+    assert_eq!(value!(1.5f32), Value::Float(1.5));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_from_f32_widens_to_float() {
+    assert_eq!(Value::from(1.5f32), Value::Float(1.5));
+    let widened = f64::try_from(Value::from(1.5f32)).expect("f32 should convert to Float");
+    assert_eq!(widened, 1.5);
+}
+
+#[test]
+fn test_value_macro_string_literal() {
+    assert_eq!(value!("hi"), Value::Str(String::from("hi")));
+    assert_eq!(
+        value!(String::from("owned")),
+        Value::Str(String::from("owned"))
+    );
+}
+
+#[test]
+fn test_value_macro_bool_and_char_literals() {
+    assert_eq!(value!(true), Value::Bool(true));
+    assert_eq!(value!('c'), Value::Char('c'));
+}
@@ -0,0 +1,55 @@
+use std::collections::BTreeSet;
+
+use bytecode::values::{OrdValue, Value};
+
+#[test]
+fn test_btree_set_accepts_nan() {
+    let set: BTreeSet<OrdValue> = [f64::NAN, 1.0, f64::NEG_INFINITY, f64::NAN, -2.5]
+        .into_iter()
+        .map(|f| OrdValue(Value::Float(f)))
+        .collect();
+
+    let floats: Vec<f64> = set.iter().filter_map(|v| v.0.as_float()).collect();
+    assert_eq!(floats.len(), 4, "Equal NaNs should be deduplicated");
+    assert_eq!(&floats[..3], &[f64::NEG_INFINITY, -2.5, 1.0]);
+    assert!(floats[3].is_nan(), "NaN should sort after every number");
+}
+
+#[test]
+fn test_total_order_distinguishes_zero_signs() {
+    let negative = OrdValue(Value::Float(-0.0));
+    let positive = OrdValue(Value::Float(0.0));
+    assert!(negative < positive);
+    assert_ne!(negative, positive);
+}
+
+#[test]
+fn test_different_types_order_by_tag() {
+    let set: BTreeSet<OrdValue> = [
+        Value::Str(String::from("a")),
+        Value::Float(0.5),
+        Value::Int(10),
+        Value::Bool(false),
+    ]
+    .into_iter()
+    .map(OrdValue::from)
+    .collect();
+
+    let ordered: Vec<Value> = set.into_iter().map(|v| v.0).collect();
+    assert_eq!(
+        ordered,
+        vec![
+            Value::Int(10),
+            Value::Float(0.5),
+            Value::Bool(false),
+            Value::Str(String::from("a")),
+        ]
+    );
+}
+
+#[test]
+fn test_same_type_uses_natural_order() {
+    assert!(OrdValue(Value::Int(-1)) < OrdValue(Value::Int(2)));
+    assert!(OrdValue(Value::Str(String::from("a"))) < OrdValue(Value::Str(String::from("b"))));
+    assert_eq!(OrdValue(Value::Char('x')), OrdValue(Value::Char('x')));
+}
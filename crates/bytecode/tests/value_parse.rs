@@ -0,0 +1,86 @@
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_parse_int_accepts_signs_and_separators() {
+    let cases = [
+        ("42", 42),
+        ("+42", 42),
+        ("-42", -42),
+        ("1_000", 1000),
+        ("-1_000_000", -1_000_000),
+        ("0", 0),
+    ];
+
+    for (input, expected) in cases {
+        let value = Value::parse_int(input).expect("Int literal should parse");
+        assert_eq!(value, Value::Int(expected), "Parsing {:?}", input);
+    }
+}
+
+#[test]
+fn test_parse_int_rejects_malformed_input() {
+    let cases = [
+        "",
+        "+",
+        "_1",
+        "1_",
+        "-_1",
+        "1.5",
+        "12a",
+        "+-1",
+        "99999999999999999999",
+    ];
+
+    for input in cases {
+        match Value::parse_int(input) {
+            Err(ValueError::ParseError {
+                target: Type::Int,
+                input: reported,
+            }) => assert_eq!(reported, input),
+            other => panic!("Expected ParseError for {:?}, got: {:?}", input, other),
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_parse_float() {
+    let value = Value::parse_float("+2.5").expect("Float literal should parse");
+    assert_eq!(value, Value::Float(2.5));
+    let value = Value::parse_float("1e3").expect("Float literal should parse");
+    assert_eq!(value, Value::Float(1000.0));
+
+    for input in ["", "1.2.3", "one"] {
+        match Value::parse_float(input) {
+            Err(ValueError::ParseError {
+                target: Type::Float,
+                ..
+            }) => {} // Expected
+            other => panic!("Expected ParseError for {:?}, got: {:?}", input, other),
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_parse_bool() {
+    assert_eq!(
+        Value::parse_bool("true").expect("Bool literal should parse"),
+        Value::Bool(true)
+    );
+    assert_eq!(
+        Value::parse_bool("false").expect("Bool literal should parse"),
+        Value::Bool(false)
+    );
+
+    for input in ["", "True", "1", "yes"] {
+        match Value::parse_bool(input) {
+            Err(ValueError::ParseError {
+                target: Type::Bool, ..
+            }) => {} // Expected
+            other => panic!("Expected ParseError for {:?}, got: {:?}", input, other),
+        }
+    }
+}
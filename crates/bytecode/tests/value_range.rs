@@ -0,0 +1,53 @@
+use bytecode::values::Value;
+
+#[test]
+fn test_exclusive_range_contains() {
+    let range = Value::Range {
+        start: 0,
+        end: 3,
+        inclusive: false,
+    };
+    assert!(range.range_contains(0));
+    assert!(range.range_contains(2));
+    assert!(!range.range_contains(3));
+    assert!(!range.range_contains(-1));
+}
+
+#[test]
+fn test_inclusive_range_contains_end() {
+    let range = Value::Range {
+        start: -2,
+        end: 2,
+        inclusive: true,
+    };
+    assert!(range.range_contains(-2));
+    assert!(range.range_contains(2));
+    assert!(!range.range_contains(3));
+}
+
+#[test]
+fn test_empty_and_non_range_values_contain_nothing() {
+    let empty = Value::Range {
+        start: 5,
+        end: 5,
+        inclusive: false,
+    };
+    assert!(!empty.range_contains(5));
+    assert!(!Value::Int(5).range_contains(5));
+}
+
+#[test]
+fn test_range_display() {
+    let exclusive = Value::Range {
+        start: 0,
+        end: 10,
+        inclusive: false,
+    };
+    let inclusive = Value::Range {
+        start: -1,
+        end: 1,
+        inclusive: true,
+    };
+    assert_eq!(exclusive.to_string(), "0..10");
+    assert_eq!(inclusive.to_string(), "-1..=1");
+}
@@ -23,7 +23,25 @@ fn assert_value_eq_roundtrip(original: &Value, roundtripped: &Value) {
         }
         (Value::Bool(b1), Value::Bool(b2)) => assert_eq!(b1, b2, "Bool values differ"),
         (Value::Str(s1), Value::Str(s2)) => assert_eq!(s1, s2, "String values differ"),
+        (Value::Symbol(s1), Value::Symbol(s2)) => assert_eq!(s1, s2, "Symbol values differ"),
+        (Value::Tuple(t1), Value::Tuple(t2)) => {
+            assert_eq!(t1.len(), t2.len(), "Tuple arities differ");
+            for (v1, v2) in t1.iter().zip(t2) {
+                assert_value_eq_roundtrip(v1, v2);
+            }
+        }
         (Value::Char(c1), Value::Char(c2)) => assert_eq!(c1, c2, "Char values differ"),
+        (Value::Bytes(b1), Value::Bytes(b2)) => assert_eq!(b1, b2, "Bytes values differ"),
+        (Value::Range { .. }, Value::Range { .. }) => {
+            assert_eq!(original, roundtripped, "Range values differ")
+        }
+        (Value::Float32(f1), Value::Float32(f2)) => {
+            assert_eq!(
+                f1.to_bits(),
+                f2.to_bits(),
+                "Float32 values differ (comparing bits)"
+            )
+        }
         _ => panic!(
             "Type mismatch: original = {:?}, roundtripped = {:?}",
             original, roundtripped
@@ -43,6 +61,33 @@ fn string_strategy() -> impl Strategy<Value = String> {
     prop::collection::vec(char_strategy(), 0..=256).prop_map(|chars| chars.into_iter().collect())
 }
 
+// Strategy for generating short strings for the leaves of nested values.
+fn short_string_strategy() -> impl Strategy<Value = String> {
+    prop::collection::vec(char_strategy(), 0..=16).prop_map(|chars| chars.into_iter().collect())
+}
+
+// Strategy for generating scalar leaves of nested values, with short strings
+// and blobs so deep trees stay cheap to encode.
+fn leaf_strategy() -> impl Strategy<Value = Value> {
+    prop_oneof![
+        any::<isize>().prop_map(Value::Int),
+        any::<f64>().prop_map(Value::Float),
+        any::<bool>().prop_map(Value::Bool),
+        short_string_strategy().prop_map(Value::Str),
+        char_strategy().prop_map(Value::Char),
+        any::<f32>().prop_map(Value::Float32),
+        prop::collection::vec(any::<u8>(), 0..=16).prop_map(Value::Bytes),
+        short_string_strategy().prop_map(Value::Symbol),
+    ]
+}
+
+// Strategy for generating tuples nested at most four levels deep.
+fn nested_strategy() -> impl Strategy<Value = Value> {
+    leaf_strategy().prop_recursive(4, 64, 8, |inner| {
+        prop::collection::vec(inner, 0..=8).prop_map(Value::Tuple)
+    })
+}
+
 proptest! {
     #![proptest_config(ProptestConfig::with_cases(2000))]
 
@@ -67,6 +112,17 @@ proptest! {
         assert_value_eq_roundtrip(&original, &roundtripped);
     }
 
+    /// Test that Float32 values roundtrip at their native 4-byte width
+    /// This includes special values like NaN, Infinity, -Infinity, and -0.0
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_float32_roundtrip(value in any::<f32>()) {
+        let original = Value::Float32(value);
+        let roundtripped = roundtrip(original.clone())
+            .expect("Float32 roundtrip should succeed");
+        assert_value_eq_roundtrip(&original, &roundtripped);
+    }
+
     /// Test that Bool values roundtrip correctly through Vec<u8> serialization
     #[test]
     #[allow(clippy::expect_used)]
@@ -87,6 +143,53 @@ proptest! {
         assert_value_eq_roundtrip(&original, &roundtripped);
     }
 
+    /// Test that Symbol values roundtrip correctly and keep their variant
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_symbol_roundtrip(value in string_strategy()) {
+        let original = Value::Symbol(value);
+        let roundtripped = roundtrip(original.clone())
+            .expect("Symbol roundtrip should succeed");
+        assert_value_eq_roundtrip(&original, &roundtripped);
+    }
+
+    /// Test that Bytes values roundtrip correctly through Vec<u8> serialization
+    /// Arbitrary bytes are allowed, including invalid UTF-8 and embedded nulls
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_bytes_roundtrip(value in prop::collection::vec(any::<u8>(), 0..=512)) {
+        let original = Value::Bytes(value);
+        let roundtripped = roundtrip(original.clone())
+            .expect("Bytes roundtrip should succeed");
+        assert_value_eq_roundtrip(&original, &roundtripped);
+    }
+
+    /// Test that Range values roundtrip correctly, including the inclusive flag
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_range_roundtrip(
+        start in any::<isize>(),
+        end in any::<isize>(),
+        inclusive in any::<bool>(),
+    ) {
+        let original = Value::Range { start, end, inclusive };
+        let roundtripped = roundtrip(original.clone())
+            .expect("Range roundtrip should succeed");
+        assert_value_eq_roundtrip(&original, &roundtripped);
+    }
+
+    /// Test that nested tuples roundtrip and re-encode to the same bytes,
+    /// so the recursive encoder is deterministic
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_nested_roundtrip(original in nested_strategy()) {
+        let bytes: Vec<u8> = original.clone().into();
+        let roundtripped = Value::try_from(bytes.clone())
+            .expect("Nested roundtrip should succeed");
+        assert_value_eq_roundtrip(&original, &roundtripped);
+        assert_eq!(Vec::<u8>::from(roundtripped), bytes);
+    }
+
     /// Test that Char values roundtrip correctly through Vec<u8> serialization
     /// Limited to u8 range (0..=255) due to current implementation constraint
     #[test]
@@ -135,6 +238,21 @@ fn test_float_with_wrong_size_returns_error() {
     }
 }
 
+#[test]
+fn test_float32_with_wrong_size_returns_error() {
+    // Float32 type tag but 8 bytes instead of required 4
+    let buffer = vec![Type::Float32 as u8, 1, 2, 3, 4, 5, 6, 7, 8];
+    let result = Value::try_from(buffer);
+    assert!(
+        result.is_err(),
+        "Float32 with wrong size should return error"
+    );
+    match result {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
 #[test]
 fn test_bool_with_wrong_size_returns_error() {
     // Bool type tag but 3 bytes instead of required 1
@@ -191,6 +309,79 @@ fn test_string_with_mismatched_length_returns_error() {
     }
 }
 
+#[test]
+fn test_string_with_max_length_prefix_returns_error() {
+    // Length of u32::MAX is within the default limit; the end offset must
+    // be computed without wrapping on 32-bit targets
+    let mut buffer = vec![Type::Str as u8];
+    buffer.extend_from_slice(&u32::MAX.to_le_bytes());
+    buffer.extend_from_slice(&[65, 66, 67]);
+    let result = Value::try_from(buffer);
+    match result {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_string_with_invalid_utf8_returns_error() {
+    // String type tag with a lone continuation byte and an overlong encoding
+    let mut buffer = vec![Type::Str as u8];
+    buffer.extend_from_slice(&3u32.to_le_bytes());
+    buffer.extend_from_slice(&[0x80, 0xC0, 0xAF]);
+    let result = Value::try_from(buffer);
+    assert!(
+        result.is_err(),
+        "String with invalid UTF-8 should return error"
+    );
+    match result {
+        Err(ValueError::InvalidUtf8) => {} // Expected
+        other => panic!("Expected InvalidUtf8 error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_range_with_wrong_size_returns_error() {
+    // Range type tag but only the start bound
+    let buffer = vec![Type::Range as u8, 1, 2, 3, 4, 5, 6, 7, 8];
+    let result = Value::try_from(buffer);
+    assert!(result.is_err(), "Range with wrong size should return error");
+    match result {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_bytes_with_mismatched_length_returns_error() {
+    // Bytes type tag with length=10 but only 3 bytes of data
+    let buffer = vec![Type::Bytes as u8, 10, 0, 1, 2];
+    let result = Value::try_from(buffer);
+    assert!(
+        result.is_err(),
+        "Bytes with mismatched length should return error"
+    );
+    match result {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_bytes_with_truncated_length_returns_error() {
+    // Bytes type tag with a length prefix whose continuation bit is never cleared
+    let buffer = vec![Type::Bytes as u8, 0x80, 0x80];
+    let result = Value::try_from(buffer);
+    assert!(
+        result.is_err(),
+        "Bytes with truncated length should return error"
+    );
+    match result {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
 #[test]
 fn test_invalid_type_tag_returns_error() {
     // Invalid type tag (not in range 0x20..=0x24)
@@ -226,6 +417,29 @@ fn test_float_special_values_roundtrip() {
     }
 }
 
+#[test]
+#[allow(clippy::expect_used)]
+fn test_float32_special_values_roundtrip() {
+    let special_values = vec![
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        0.0,
+        -0.0,
+        f32::MIN,
+        f32::MAX,
+        f32::EPSILON,
+    ];
+
+    for &value in &special_values {
+        let original = Value::Float32(value);
+        let bytes: Vec<u8> = original.clone().into();
+        assert_eq!(bytes.len(), 5, "Float32 should encode as tag + 4 bytes");
+        let roundtripped = roundtrip(original.clone()).expect("Float32 roundtrip should succeed");
+        assert_value_eq_roundtrip(&original, &roundtripped);
+    }
+}
+
 #[test]
 #[allow(clippy::expect_used)]
 fn test_string_edge_cases_roundtrip() {
@@ -245,6 +459,61 @@ fn test_string_edge_cases_roundtrip() {
     }
 }
 
+#[test]
+#[allow(clippy::expect_used)]
+fn test_bytes_edge_cases_roundtrip() {
+    let test_cases = vec![
+        Vec::new(),             // Empty
+        vec![0, 0, 0],          // Embedded nulls
+        vec![0xff, 0xfe, 0x80], // Invalid UTF-8
+        vec![7; 128],           // Length needing a two-byte prefix
+        vec![1; 1000],          // Long blob
+    ];
+
+    for value in test_cases {
+        let original = Value::Bytes(value);
+        let roundtripped = roundtrip(original.clone()).expect("Bytes roundtrip should succeed");
+        assert_value_eq_roundtrip(&original, &roundtripped);
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_range_inclusive_flag_roundtrip() {
+    for inclusive in [false, true] {
+        let original = Value::Range {
+            start: -5,
+            end: 5,
+            inclusive,
+        };
+        let bytes: Vec<u8> = original.clone().into();
+        assert_eq!(bytes.len(), 18, "Range should encode as tag + 17 bytes");
+        assert_eq!(bytes[17], inclusive as u8);
+        let roundtripped = roundtrip(original.clone()).expect("Range roundtrip should succeed");
+        assert_value_eq_roundtrip(&original, &roundtripped);
+    }
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_symbol_encodes_like_str_under_its_own_tag() {
+    let symbol: Vec<u8> = Value::Symbol(String::from("x")).into();
+    let string: Vec<u8> = Value::Str(String::from("x")).into();
+    assert_eq!(symbol[0], Type::Symbol as u8);
+    assert_eq!(symbol[1..], string[1..]);
+
+    let roundtripped =
+        roundtrip(Value::Symbol(String::from("x"))).expect("Symbol roundtrip should succeed");
+    assert_eq!(roundtripped, Value::Symbol(String::from("x")));
+}
+
+#[test]
+fn test_bytes_length_prefix_is_leb128() {
+    let bytes: Vec<u8> = Value::Bytes(vec![7; 128]).into();
+    assert_eq!(&bytes[..3], &[Type::Bytes as u8, 0x80, 0x01]);
+    assert_eq!(bytes.len(), 3 + 128);
+}
+
 #[test]
 #[allow(clippy::expect_used)]
 fn test_int_edge_cases_roundtrip() {
@@ -256,3 +525,64 @@ fn test_int_edge_cases_roundtrip() {
         assert_value_eq_roundtrip(&original, &roundtripped);
     }
 }
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_decode_from_borrowed_slice() {
+    let mut buffer = vec![0xAA, 0xBB];
+    buffer.extend(Vec::<u8>::from(Value::Str(String::from("borrowed"))));
+    let decoded = Value::try_from(&buffer[2..]).expect("Slice decode should succeed");
+    assert_eq!(decoded, Value::Str(String::from("borrowed")));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_decode_prefix_reports_consumed_bytes() {
+    let mut buffer: Vec<u8> = Value::Int(7).into();
+    buffer.extend_from_slice(&[1, 2, 3]);
+    let (value, consumed) = Value::decode_prefix(&buffer).expect("Prefix decode should succeed");
+    assert_eq!(value, Value::Int(7));
+    assert_eq!(consumed, 9);
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_decode_prefix_reads_back_to_back_values() {
+    let first = Value::Str(String::from("first"));
+    let second = Value::Float(2.5);
+    let mut buffer: Vec<u8> = first.clone().into();
+    buffer.extend(Vec::<u8>::from(second.clone()));
+
+    let (decoded, consumed) =
+        Value::decode_prefix(&buffer).expect("First prefix decode should succeed");
+    assert_eq!(decoded, first);
+    let (decoded, rest) =
+        Value::decode_prefix(&buffer[consumed..]).expect("Second prefix decode should succeed");
+    assert_eq!(decoded, second);
+    assert_eq!(
+        consumed + rest,
+        buffer.len(),
+        "Both values should be consumed"
+    );
+}
+
+#[test]
+fn test_whole_buffer_decode_rejects_trailing_value() {
+    let mut buffer: Vec<u8> = Value::Bool(true).into();
+    buffer.extend(Vec::<u8>::from(Value::Bool(false)));
+    let result = Value::try_from(buffer);
+    match result {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_decode_prefix_with_truncated_value_returns_error() {
+    let buffer: Vec<u8> = Value::Int(1).into();
+    let result = Value::decode_prefix(&buffer[..5]);
+    match result {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
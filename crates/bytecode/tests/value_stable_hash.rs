@@ -0,0 +1,38 @@
+use bytecode::values::Value;
+
+#[test]
+fn test_stable_hash_is_fixed() {
+    assert_eq!(Value::Int(42).stable_hash(), 0x6395_2f0b_c5ef_3df5);
+    assert_eq!(
+        Value::Str(String::from("hi")).stable_hash(),
+        0xd9e9_a4ee_f271_6b53
+    );
+}
+
+#[test]
+fn test_stable_hash_separates_types() {
+    let symbol = Value::Symbol(String::from("hi"));
+    let string = Value::Str(String::from("hi"));
+    assert_ne!(symbol.stable_hash(), string.stable_hash());
+    assert_ne!(Value::Int(1).stable_hash(), Value::Float(1.0).stable_hash());
+}
+
+#[test]
+fn test_stable_hash_follows_canonicalize() {
+    let mut negative = Value::Float(-0.0);
+    assert_ne!(negative.stable_hash(), Value::Float(0.0).stable_hash());
+    negative.canonicalize();
+    assert_eq!(negative.stable_hash(), Value::Float(0.0).stable_hash());
+}
+
+#[test]
+fn test_stable_hash_keeps_full_char() {
+    // U+1F961 shares its low byte (0x61) with 'a'.
+    let wide = Value::Char('\u{1f961}');
+    let narrow = Value::Char('a');
+    assert_ne!(wide.stable_hash(), narrow.stable_hash());
+    assert_ne!(
+        Value::Tuple(vec![wide]).stable_hash(),
+        Value::Tuple(vec![narrow]).stable_hash()
+    );
+}
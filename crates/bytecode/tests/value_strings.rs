@@ -0,0 +1,103 @@
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_str_len_counts_chars_not_bytes() {
+    let value = Value::Str(String::from("a🦀b"));
+    let len = value.str_len().expect("str_len should succeed");
+    assert_eq!(len, Value::Int(3));
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_str_char_at_indexes_by_char() {
+    let value = Value::Str(String::from("a🦀b"));
+    let crab = value
+        .str_char_at(&Value::Int(1))
+        .expect("str_char_at should succeed");
+    assert_eq!(crab, Value::Char('🦀'));
+    let last = value
+        .str_char_at(&Value::Int(2))
+        .expect("str_char_at should succeed");
+    assert_eq!(last, Value::Char('b'));
+}
+
+#[test]
+fn test_str_char_at_out_of_bounds_returns_error() {
+    let value = Value::Str(String::from("a🦀b"));
+    for index in [3, -1] {
+        match value.str_char_at(&Value::Int(index)) {
+            Err(ValueError::IndexOutOfBounds { index: i, len: 3 }) => assert_eq!(i, index),
+            other => panic!("Expected IndexOutOfBounds error, got: {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_string_ops_on_wrong_types_return_error() {
+    match Value::Int(1).str_len() {
+        Err(ValueError::InvalidOperand {
+            operand: Type::Int, ..
+        }) => {} // Expected
+        other => panic!("Expected InvalidOperand error, got: {:?}", other),
+    }
+
+    let result = Value::Str(String::from("abc")).str_char_at(&Value::Float(0.0));
+    assert!(
+        matches!(result, Err(ValueError::InvalidOperation { .. })),
+        "Expected InvalidOperation error, got: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_len_counts_collection_elements() {
+    assert_eq!(Value::Str(String::from("héllo")).len(), Some(5));
+    assert_eq!(Value::Bytes(vec![0xC3, 0xA9]).len(), Some(2));
+    assert_eq!(
+        Value::Tuple(vec![Value::Int(1), Value::Tuple(Vec::new())]).len(),
+        Some(2)
+    );
+    assert_eq!(Value::Tuple(Vec::new()).len(), Some(0));
+}
+
+#[test]
+fn test_len_is_none_for_scalars() {
+    assert_eq!(Value::Int(3).len(), None);
+    assert_eq!(Value::Char('a').len(), None);
+    assert_eq!(Value::Symbol(String::from("abc")).len(), None);
+}
+
+#[test]
+fn test_is_empty_follows_len() {
+    assert_eq!(Value::Str(String::new()).is_empty(), Some(true));
+    assert_eq!(Value::Bytes(vec![1]).is_empty(), Some(false));
+    assert_eq!(Value::Bool(false).is_empty(), None);
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_len_value_matches_str_len_for_strings() {
+    let s = Value::Str(String::from("héllo"));
+    let len = s.len_value().expect("Str has a length");
+    assert_eq!(len, Value::Int(5));
+    assert_eq!(len, s.str_len().expect("Str has a length"));
+
+    let tuple = Value::Tuple(vec![Value::Int(1), Value::Int(2), Value::Int(3)]);
+    assert_eq!(
+        tuple.len_value().expect("Tuple has a length"),
+        Value::Int(3)
+    );
+}
+
+#[test]
+fn test_len_value_rejects_scalars() {
+    match Value::Float(1.0).len_value() {
+        Err(ValueError::InvalidOperand { op, operand }) => {
+            assert_eq!(op, "length");
+            assert_eq!(operand, Type::Float);
+        }
+        other => panic!("Expected InvalidOperand error, got: {:?}", other),
+    }
+}
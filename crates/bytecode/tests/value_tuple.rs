@@ -0,0 +1,119 @@
+use bytecode::types::Type;
+use bytecode::values::{OrdValue, Value, ValueError};
+
+fn pair() -> Value {
+    Value::Tuple(vec![Value::Int(1), Value::Str(String::from("one"))])
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_tuple_roundtrip() {
+    let nested = Value::Tuple(vec![
+        pair(),
+        Value::Tuple(Vec::new()),
+        Value::Float(f64::NAN),
+        Value::Symbol(String::from("x")),
+    ]);
+    let bytes: Vec<u8> = nested.clone().into();
+    let decoded = Value::try_from(bytes).expect("Tuple roundtrip should succeed");
+
+    let Value::Tuple(items) = decoded else {
+        panic!("Expected Tuple, got: {:?}", decoded);
+    };
+    assert_eq!(items.len(), 4);
+    assert_eq!(items[0], pair());
+    assert_eq!(items[1], Value::Tuple(Vec::new()));
+    assert!(items[2].is_nan());
+    assert_eq!(items[3], Value::Symbol(String::from("x")));
+}
+
+#[test]
+fn test_tuple_encoding_is_count_then_elements() {
+    let bytes: Vec<u8> = pair().into();
+    let mut expected = vec![Type::Tuple as u8, 2];
+    expected.extend(Vec::<u8>::from(Value::Int(1)));
+    expected.extend(Vec::<u8>::from(Value::Str(String::from("one"))));
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn test_tuple_with_missing_elements_returns_error() {
+    let mut bytes: Vec<u8> = pair().into();
+    bytes[1] = 3;
+    match Value::try_from(bytes) {
+        Err(ValueError::NoTag) => {} // Expected: the third element is absent
+        other => panic!("Expected NoTag error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_deeply_nested_tuple_is_rejected() {
+    let depth = Value::MAX_NESTING_DEPTH + 1;
+    let mut bytes = [Type::Tuple as u8, 1].repeat(depth);
+    bytes.extend([Type::Tuple as u8, 0]);
+    match Value::try_from(bytes) {
+        Err(ValueError::NestingTooDeep { max }) => assert_eq!(max, Value::MAX_NESTING_DEPTH),
+        other => panic!("Expected NestingTooDeep error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_tuple_equality_is_positional() {
+    let swapped = Value::Tuple(vec![Value::Str(String::from("one")), Value::Int(1)]);
+    assert_ne!(pair(), swapped);
+    assert_eq!(pair(), pair());
+}
+
+#[test]
+fn test_tuple_display() {
+    assert_eq!(pair().to_string(), "(1, \"one\")");
+    assert_eq!(Value::Tuple(vec![Value::Bool(true)]).to_string(), "(true,)");
+    assert_eq!(Value::Tuple(Vec::new()).to_string(), "()");
+}
+
+#[test]
+fn test_tuple_display_quotes_text_elements() {
+    let tuple = Value::Tuple(vec![Value::Str(String::from("a, b"))]);
+    assert_eq!(tuple.to_string(), "(\"a, b\",)");
+    let tuple = Value::Tuple(vec![Value::Symbol(String::from("s")), Value::Char('c')]);
+    assert_eq!(tuple.to_string(), "(\"s\", 'c')");
+}
+
+#[test]
+fn test_tuple_total_order_is_lexicographic() {
+    let short = OrdValue(Value::Tuple(vec![Value::Int(1)]));
+    let long = OrdValue(Value::Tuple(vec![Value::Int(1), Value::Int(0)]));
+    let bigger = OrdValue(Value::Tuple(vec![Value::Int(2)]));
+    assert!(short < long);
+    assert!(long < bigger);
+}
+
+#[test]
+fn test_map_deep_rewrites_nested_values() {
+    let nested = Value::Tuple(vec![
+        Value::Int(1),
+        Value::Tuple(vec![Value::Int(2), Value::Str(String::from("keep"))]),
+    ]);
+    let incremented = nested.map_deep(|value| match value {
+        Value::Int(i) => Value::Int(i + 1),
+        other => other,
+    });
+    assert_eq!(
+        incremented,
+        Value::Tuple(vec![
+            Value::Int(2),
+            Value::Tuple(vec![Value::Int(3), Value::Str(String::from("keep"))]),
+        ])
+    );
+}
+
+#[test]
+fn test_map_deep_visits_children_before_parents() {
+    let nested = Value::Tuple(vec![Value::Tuple(vec![Value::Int(1)])]);
+    let mut visited = Vec::new();
+    nested.map_deep(|value| {
+        visited.push(value.to_string());
+        value
+    });
+    assert_eq!(visited, ["1", "(1,)", "((1,),)"]);
+}
@@ -0,0 +1,94 @@
+use bytecode::byte_order::ByteOrder;
+use bytecode::encoding::{Encoding, IntEncoding};
+use bytecode::types::Type;
+use bytecode::values::{Value, ValueError};
+use proptest::prelude::*;
+
+const LEB128: Encoding = Encoding {
+    order: ByteOrder::Little,
+    ints: IntEncoding::Leb128,
+};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    /// Test that Int values roundtrip correctly in LEB128 mode
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_int_leb128_roundtrip(value in any::<isize>()) {
+        let original = Value::Int(value);
+        let bytes = original.encode_as(LEB128);
+        let roundtripped = Value::decode_as(&bytes, LEB128)
+            .expect("LEB128 Int roundtrip should succeed");
+        prop_assert_eq!(roundtripped, original);
+        prop_assert!(bytes.len() <= 11, "LEB128 Int should take at most 10 bytes");
+    }
+}
+
+#[test]
+fn test_small_ints_take_one_byte() {
+    for value in [0, 1, -1, 63, -64] {
+        let bytes = Value::Int(value).encode_as(LEB128);
+        assert_eq!(bytes.len(), 2, "{} should encode as tag + 1 byte", value);
+    }
+
+    for value in [64, -65] {
+        let bytes = Value::Int(value).encode_as(LEB128);
+        assert_eq!(bytes.len(), 3, "{} should encode as tag + 2 bytes", value);
+    }
+}
+
+#[test]
+fn test_leb128_int_layout() {
+    assert_eq!(
+        Value::Int(-1).encode_as(LEB128),
+        vec![Type::Int as u8, 0x7f]
+    );
+    assert_eq!(
+        Value::Int(128).encode_as(LEB128),
+        vec![Type::Int as u8, 0x80, 0x01]
+    );
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_edge_ints_roundtrip_in_leb128_mode() {
+    for value in [isize::MIN, isize::MAX, 0, -1, 1] {
+        let bytes = Value::Int(value).encode_as(LEB128);
+        let decoded = Value::decode_as(&bytes, LEB128).expect("Decode should succeed");
+        assert_eq!(decoded, Value::Int(value));
+    }
+}
+
+#[test]
+fn test_truncated_leb128_int_returns_error() {
+    let result = Value::decode_as(&[Type::Int as u8, 0x80], LEB128);
+    match result {
+        Err(ValueError::IncompatibleSize) => {} // Expected
+        other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_overlong_leb128_int_returns_error() {
+    let mut zero_high_bits = vec![Type::Int as u8];
+    zero_high_bits.extend([0x80; 9]);
+    zero_high_bits.push(0x02);
+    let mut minus_one_high_bits = vec![Type::Int as u8];
+    minus_one_high_bits.extend([0xff; 9]);
+    minus_one_high_bits.push(0x3f);
+
+    for bytes in [zero_high_bits, minus_one_high_bits] {
+        match Value::decode_as(&bytes, LEB128) {
+            Err(ValueError::IncompatibleSize) => {} // Expected
+            other => panic!("Expected IncompatibleSize error, got: {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_default_encoding_stays_fixed_width() {
+    let value = Value::Int(1);
+    assert_eq!(Encoding::default().ints, IntEncoding::Fixed);
+    assert_eq!(value.encode_as(Encoding::default()), Vec::<u8>::from(value));
+}
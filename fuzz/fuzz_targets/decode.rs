@@ -0,0 +1,19 @@
+//! Feeds arbitrary bytes to the value decoders; any input must produce
+//! `Ok` or `Err`, never a panic.
+//!
+//! Run from the repository root with a nightly toolchain:
+//!
+//! ```text
+//! cargo install cargo-fuzz
+//! cargo +nightly fuzz run decode
+//! ```
+#![no_main]
+
+use bytecode::values::Value;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Value::try_from(data);
+    let _ = Value::decode_prefix(data);
+    let _ = Value::decode_with_limit(data, 64);
+});